@@ -0,0 +1,76 @@
+//! Benchmarks every registered day on its default input, timing the parsing and each
+//! part separately. Days whose input is missing are skipped.
+//!
+//! ```bash
+//! cargo bench --bench days -- day05
+//! cargo bench --bench days -- --save-baseline main
+//! cargo bench --bench days -- --baseline main
+//! ```
+
+use std::{hint::black_box, time::Duration};
+
+use aoc22::{
+    days::{
+        day12::{self, Day12},
+        Day, DAYS,
+    },
+    input::InputSource,
+    solution::Solution,
+};
+use criterion::{criterion_group, criterion_main, Criterion, SamplingMode};
+
+fn read_input(day: &Day) -> Option<String> {
+    match InputSource::new(day.number, None).read() {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("skipping {}: {}", day.name, e);
+            None
+        }
+    }
+}
+
+fn bench_days(c: &mut Criterion) {
+    for day in DAYS {
+        let Some(input) = read_input(day) else {
+            continue;
+        };
+        let parsed = (day.parse)(&input).unwrap_or_else(|e| panic!("{}", e));
+        let mut group = c.benchmark_group(day.name);
+        // Some parts take seconds: few samples of one iteration each keep the run bearable
+        group.sampling_mode(SamplingMode::Flat);
+        group.sample_size(10);
+        group.warm_up_time(Duration::from_millis(500));
+        group.bench_function("parse", |b| b.iter(|| (day.parse)(black_box(&input))));
+        group.bench_function("part1", |b| b.iter(|| parsed.part1()));
+        group.bench_function("part2", |b| b.iter(|| parsed.part2()));
+        group.finish();
+    }
+}
+
+/// Compares the two path-finding strategies of day 12.
+fn bench_day12_variants(c: &mut Criterion) {
+    let Some(day) = DAYS.iter().find(|day| day.name == "day12") else {
+        return;
+    };
+    let Some(input) = read_input(day) else {
+        return;
+    };
+    let grid = Day12::parse(&input).unwrap_or_else(|e| panic!("{}", e));
+    let mut group = c.benchmark_group("day12-variants");
+    group.bench_function("part1_dijkstra", |b| {
+        b.iter(|| day12::part1_dijsktra(black_box(&grid)))
+    });
+    group.bench_function("part1_bfs", |b| {
+        b.iter(|| day12::part1_bfs(black_box(&grid)))
+    });
+    group.bench_function("part2_dijkstra", |b| {
+        b.iter(|| day12::part2_dijsktra(black_box(&grid)))
+    });
+    group.bench_function("part2_bfs", |b| {
+        b.iter(|| day12::part2_bfs(black_box(&grid)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_days, bench_day12_variants);
+criterion_main!(benches);
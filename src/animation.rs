@@ -0,0 +1,189 @@
+//! Frames of the simulations, played in the terminal by `aoc run --animate` or saved to
+//! files with `--export`.
+//!
+//! A day becomes animated by implementing [`Animated`], producing its frames lazily from a
+//! grid of cells that know how to draw themselves, as a [`Glyph`] in the terminal and with
+//! a [`Palette`] colour in images.
+
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{Error, Result},
+    grid::Grid,
+    solution::Solution,
+};
+
+/// How a cell is drawn in the terminal.
+pub trait Glyph {
+    fn glyph(&self) -> &'static str;
+}
+
+impl<T: Glyph> Glyph for &T {
+    fn glyph(&self) -> &'static str {
+        (*self).glyph()
+    }
+}
+
+/// A colour, as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// How a cell is coloured in images.
+pub trait Palette {
+    fn colour(&self) -> Rgb;
+}
+
+impl<T: Palette> Palette for &T {
+    fn colour(&self) -> Rgb {
+        (*self).colour()
+    }
+}
+
+/// One picture of a simulation: its cells, drawn and coloured, under a caption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub glyphs: Grid<&'static str>,
+    pub colours: Grid<Rgb>,
+}
+
+impl Frame {
+    pub fn new<T: Glyph + Palette>(caption: impl Into<String>, cells: &Grid<T>) -> Self {
+        Frame {
+            caption: caption.into(),
+            glyphs: cells.map(T::glyph),
+            colours: cells.map(T::colour),
+        }
+    }
+}
+
+/// The caption on its own line, then the cells row by row.
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+        self.glyphs.fmt(f)
+    }
+}
+
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// A solution whose simulation can be watched.
+pub trait Animated: Solution {
+    /// The frames of the simulation, computed as they are consumed. They all have the same
+    /// size, so that they can be assembled into a GIF.
+    fn frames(input: Self::Input) -> Frames;
+}
+
+/// Parses `input` and starts the animation of `S`.
+pub fn frames<S: Animated>(input: &str) -> Result<Frames> {
+    Ok(S::frames(S::parse(input)?))
+}
+
+/// Redraws frames in place in a terminal, at a steady rate.
+pub struct Player<W: Write> {
+    out: W,
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+impl<W: Write> Player<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Player {
+            out,
+            interval: Duration::from_secs(1) / fps.max(1),
+            next: None,
+        }
+    }
+
+    /// Waits for the time of `frame`, then draws it over the previous one.
+    pub fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        match self.next {
+            // Clear the screen and hide the cursor before the first frame
+            None => write!(self.out, "\x1b[2J\x1b[?25l")?,
+            Some(next) => thread::sleep(next.saturating_duration_since(Instant::now())),
+        }
+        self.next = Some(Instant::now() + self.interval);
+        // Back to the top left corner, drawing over the previous frame and clearing the rest
+        write!(self.out, "\x1b[H{}\x1b[J", frame)?;
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Drop for Player<W> {
+    fn drop(&mut self) {
+        if self.next.is_some() {
+            let _ = writeln!(self.out, "\x1b[?25h");
+        }
+    }
+}
+
+/// Saves `frame` as the text file `frameNNNN.txt` of `dir`, numbered from `index`.
+pub fn save_text(frame: &Frame, dir: &Path, index: usize) -> Result<()> {
+    fs::create_dir_all(dir).map_err(|e| Error::file(dir, e))?;
+    let path = dir.join(format!("frame{:04}.txt", index));
+    fs::write(&path, format!("{}\n", frame)).map_err(|e| Error::file(&path, e))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    enum Cell {
+        Empty,
+        Full,
+    }
+
+    impl Glyph for Cell {
+        fn glyph(&self) -> &'static str {
+            match self {
+                Cell::Empty => ".",
+                Cell::Full => "#",
+            }
+        }
+    }
+
+    impl Palette for Cell {
+        fn colour(&self) -> Rgb {
+            match self {
+                Cell::Empty => [255, 255, 255],
+                Cell::Full => [0, 0, 0],
+            }
+        }
+    }
+
+    pub(crate) fn frame(caption: &str) -> Frame {
+        let cells = Grid::from_rows([[Cell::Full, Cell::Empty], [Cell::Empty, Cell::Full]]);
+        Frame::new(caption, &cells)
+    }
+
+    #[test]
+    fn test_frame() {
+        assert_eq!(frame("Step 1").to_string(), "Step 1\n#.\n.#");
+        assert_eq!(frame("").to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_player() {
+        let mut out = vec![];
+        {
+            let mut player = Player::new(&mut out, 1000);
+            player.show(&frame("1")).unwrap();
+            player.show(&frame("2")).unwrap();
+        }
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out,
+            "\x1b[2J\x1b[?25l\x1b[H1\n#.\n.#\x1b[J\x1b[H2\n#.\n.#\x1b[J\x1b[?25h\n"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    path::Path,
+};
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+/// Default location of the checked-in answers, relative to the repository root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// A known answer, written in TOML either as an integer or as a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Integer(i64),
+    Text(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Integer(n) => write!(f, "{}", n),
+            Expected::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+/// The known answers of each day, keyed by day name:
+///
+/// ```toml
+/// [day01]
+/// part1 = 67622
+/// part2 = 201491
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerSheet {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+/// Outcome of comparing a computed answer with the answer sheet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl AnswerSheet {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| Error::file(path, e))?;
+        toml::from_str(&content).map_err(|e| Error::file(path, e))
+    }
+
+    pub fn get(&self, day: &str, part: u8) -> Option<&Expected> {
+        let answers = self.days.get(day)?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, day: &str, part: u8, actual: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) => {
+                let expected = expected.to_string();
+                if expected.trim_end() == actual.trim_end() {
+                    Verdict::Pass
+                } else {
+                    Verdict::Fail { expected }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let sheet: AnswerSheet = toml::from_str(
+            "[day05]\npart1 = \"CMZ\"\n\n[day10]\npart1 = 13140\npart2 = '''\n#.\n.#\n'''\n",
+        )
+        .unwrap();
+        assert_eq!(sheet.check("day05", 1, "CMZ"), Verdict::Pass);
+        assert_eq!(sheet.check("day05", 2, "MCD"), Verdict::Unknown);
+        assert_eq!(sheet.check("day10", 1, "13140"), Verdict::Pass);
+        assert_eq!(sheet.check("day10", 2, "#.\n.#"), Verdict::Pass);
+        assert_eq!(
+            sheet.check("day10", 1, "13141"),
+            Verdict::Fail {
+                expected: "13140".to_string()
+            }
+        );
+        assert_eq!(sheet.check("day11", 1, "10605"), Verdict::Unknown);
+    }
+}
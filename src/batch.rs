@@ -0,0 +1,153 @@
+//! Running jobs concurrently on a rayon thread pool, such as the days of
+//! `aoc run --all --parallel`, while reporting their results in order.
+//!
+//! A job that panics or runs out of time is reported as such without stopping the others.
+//! Threads cannot be killed: a job that timed out keeps running until it ends or the process
+//! exits, and its result is dropped. Each job has a worker of its own, so that it never waits
+//! behind one that timed out, and rayon shares the cores between them.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// How a job ended.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    /// The job panicked, with this message
+    Panicked(String),
+    /// The job was still running after the timeout
+    TimedOut(Duration),
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+/// Starts `job` on each of `items`, each on a worker of a new rayon thread pool. The outcomes
+/// come in the order of `items`, each as soon as it and the ones before it are known. The
+/// `timeout` of a job counts from its start.
+pub fn run_ordered<I, T>(
+    items: Vec<I>,
+    timeout: Option<Duration>,
+    job: impl Fn(&I) -> T + Send + Sync + 'static,
+) -> impl Iterator<Item = Outcome<T>>
+where
+    I: Send + Sync + 'static,
+    T: Send + 'static,
+{
+    let count = items.len();
+    let items = Arc::new(items);
+    let job = Arc::new(job);
+    let started = Arc::new(Mutex::new(vec![None; count]));
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(count.max(1))
+        .thread_name(|index| format!("batch-{}", index))
+        .build()
+        .expect("could not start the worker threads");
+    let (sender, receiver) = mpsc::channel();
+    for index in 0..count {
+        let (items, job, started, sender) =
+            (items.clone(), job.clone(), started.clone(), sender.clone());
+        pool.spawn(move || {
+            started.lock().unwrap()[index] = Some(Instant::now());
+            let result = panic::catch_unwind(AssertUnwindSafe(|| job(&items[index])));
+            let outcome = match result {
+                Ok(value) => Outcome::Done(value),
+                Err(payload) => Outcome::Panicked(panic_message(payload)),
+            };
+            // The receiver is gone when the outcomes are no longer wanted
+            let _ = sender.send((index, outcome));
+        });
+    }
+
+    let mut outcomes: Vec<Option<Outcome<T>>> = (0..count).map(|_| None).collect();
+    let mut next = 0;
+    std::iter::from_fn(move || {
+        while next < count && outcomes[next].is_none() {
+            // Wait for a result, or for the earliest running job to run out of time
+            let deadline = timeout.and_then(|timeout| {
+                let started = started.lock().unwrap();
+                (0..count)
+                    .filter(|&i| outcomes[i].is_none())
+                    .filter_map(|i| started[i])
+                    .min()
+                    .map(|start| start + timeout)
+            });
+            // Every job sends its outcome, so the channel only closes after they all did
+            let received = match deadline {
+                Some(deadline) => {
+                    let wait = deadline.saturating_duration_since(Instant::now());
+                    match receiver.recv_timeout(wait) {
+                        Ok(received) => Some(received),
+                        Err(mpsc::RecvTimeoutError::Timeout) => None,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => return None,
+                    }
+                }
+                None => Some(receiver.recv().ok()?),
+            };
+            if let Some((index, outcome)) = received {
+                outcomes[index].get_or_insert(outcome);
+            }
+            if let Some(timeout) = timeout {
+                let started = started.lock().unwrap();
+                for (outcome, start) in outcomes.iter_mut().zip(started.iter()) {
+                    if outcome.is_none() && start.is_some_and(|s| s.elapsed() >= timeout) {
+                        *outcome = Some(Outcome::TimedOut(timeout));
+                    }
+                }
+            }
+        }
+        let outcome = outcomes.get_mut(next)?.take();
+        next += 1;
+        outcome
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        // Later items finish first
+        let outcomes = run_ordered(vec![30, 20, 10, 0], None, |&ms| {
+            thread::sleep(Duration::from_millis(ms));
+            ms * 2
+        });
+        assert_eq!(
+            outcomes.collect::<Vec<_>>(),
+            [60, 40, 20, 0].map(Outcome::Done)
+        );
+    }
+
+    #[test]
+    fn test_isolation() {
+        let timeout = Duration::from_millis(100);
+        let outcomes = run_ordered(vec![0, 1, 2], Some(timeout), |&i| match i {
+            0 => panic!("Pattern not found"),
+            1 => thread::sleep(Duration::from_secs(2)),
+            _ => (),
+        });
+        assert_eq!(
+            outcomes.collect::<Vec<_>>(),
+            [
+                Outcome::Panicked("Pattern not found".to_string()),
+                Outcome::TimedOut(timeout),
+                Outcome::Done(()),
+            ]
+        );
+    }
+}
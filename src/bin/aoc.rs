@@ -0,0 +1,575 @@
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc22::{
+    animation::{self, Player},
+    answers::{AnswerSheet, Verdict, ANSWERS_FILE},
+    batch::{self, Outcome as BatchOutcome},
+    client::{self, AocClient, Config, Feedback, Fetched, Outcome, CONFIG_FILE},
+    days::{self, Day},
+    error::Error,
+    export::{self, GifWriter},
+    input::{input_dir, input_path, InputSource},
+    progress, scaffold,
+    solution::{Answer, Answers, Result},
+    submissions::{Known, SubmissionLog, SUBMISSIONS_FILE},
+    timing::{self, format_duration, Timing},
+    trace::{self, Level},
+};
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of one day, or of the whole calendar
+    #[command(group(ArgGroup::new("target").required(true).args(["day", "all"])))]
+    Run {
+        /// Day number (e.g. 17) or name (e.g. day11-bigint)
+        day: Option<String>,
+        /// Run every registered day
+        #[arg(long)]
+        all: bool,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file, or from stdin with `-`
+        /// [default: $AOC_INPUT_DIR/dayXX.txt, or inputs/dayXX.txt]
+        #[arg(short, long, conflicts_with = "all")]
+        input: Option<String>,
+        /// Print how long each day took instead of the answers
+        #[arg(long)]
+        time: bool,
+        /// Format of the timing report
+        #[arg(long, value_enum, default_value_t = Format::Table, requires = "time")]
+        format: Format,
+        /// Sort the timing report from the slowest day to the fastest
+        #[arg(long, requires = "time")]
+        sort: bool,
+        /// Run the days concurrently, still reporting them in order. A panicking day is
+        /// reported as a failure instead of stopping the others
+        #[arg(long, requires = "all")]
+        parallel: bool,
+        /// Give up on a day still running after this many seconds
+        #[arg(long, value_name = "SECONDS", requires = "parallel",
+              value_parser = clap::value_parser!(u64).range(1..))]
+        timeout: Option<u64>,
+        /// Trace the steps of the solutions on stderr, `steps` when no level is given
+        #[arg(
+            long,
+            value_enum,
+            value_name = "LEVEL",
+            num_args = 0..=1,
+            default_missing_value = "steps"
+        )]
+        trace: Option<Level>,
+        /// Hide the progress bars of the long searches, which are only drawn on a terminal
+        /// and never with --time or --trace
+        #[arg(long)]
+        no_progress: bool,
+        /// Play the simulation of the day in the terminal instead of printing the answers
+        #[arg(long, conflicts_with_all = ["all", "part", "time"])]
+        animate: bool,
+        /// Frames per second of the animation, and of the exported GIF
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        fps: u32,
+        /// Save the frames of the simulation in this directory, as frameNNNN.txt,
+        /// frameNNNN.png or dayXX.gif
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["all", "part", "time"])]
+        export: Option<PathBuf>,
+        /// How the frames are exported
+        #[arg(long, value_enum, default_value_t = ExportFormat::Txt, requires = "export")]
+        export_format: ExportFormat,
+        /// Size in pixels of a cell in exported images
+        #[arg(
+            long,
+            default_value_t = 4,
+            requires = "export",
+            value_parser = clap::value_parser!(u32).range(1..=64)
+        )]
+        scale: u32,
+    },
+    /// Check the answers of every day, or of one day, against the answers file
+    Verify {
+        /// Day number (e.g. 17) or name (e.g. day11-bigint)
+        day: Option<String>,
+        /// Known answers, keyed by day name
+        #[arg(long, default_value = ANSWERS_FILE)]
+        answers: PathBuf,
+    },
+    /// Download the input of a day from adventofcode.com, unless it is already there
+    Fetch {
+        /// Day number (e.g. 17) or name (e.g. day11-bigint)
+        day: String,
+        /// Config file holding the session token, overridden by $AOC_SESSION
+        #[arg(long, default_value = CONFIG_FILE)]
+        config: PathBuf,
+    },
+    /// Compute the answer of a part and submit it to adventofcode.com
+    Submit {
+        /// Day number (e.g. 17) or name (e.g. day11-bigint)
+        day: String,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Read the input from this file, or from stdin with `-`
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Config file holding the session token, overridden by $AOC_SESSION
+        #[arg(long, default_value = CONFIG_FILE)]
+        config: PathBuf,
+        /// Where the judged submissions are recorded
+        #[arg(long, default_value = SUBMISSIONS_FILE)]
+        submissions: PathBuf,
+    },
+    /// Start the solution of a new day from the template, refusing to replace an existing one
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// List the registered days
+    List,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// One text file per frame, as drawn in the terminal
+    Txt,
+    /// One image per frame
+    Png,
+    /// A single animated GIF, looping forever
+    Gif,
+}
+
+/// Where and how `run --export` saves the frames.
+struct Export<'a> {
+    dir: &'a Path,
+    format: ExportFormat,
+    scale: usize,
+    fps: u32,
+}
+
+/// What `run` prints: the answers of each day as they come, or a timing report at the end.
+enum Report {
+    Answers,
+    Timings { format: Format, sort: bool },
+}
+
+fn run_day(day: &Day, part: Option<u8>, input: Option<&str>) -> Result<Answers> {
+    let input = InputSource::new(day.number, input).read()?;
+    day.solve(&input, part)
+}
+
+fn print_answers(day: &Day, answers: Answers) {
+    for (index, answer) in [(1, answers.part1), (2, answers.part2)] {
+        if let Some(Answer { value, .. }) = answer {
+            let separator = if value.contains('\n') { "\n" } else { " " };
+            println!("{} part{}:{}{}", day.name, index, separator, value);
+        }
+    }
+}
+
+fn print_timings(mut timings: Vec<Timing>, format: Format, sort: bool) {
+    if sort {
+        timings.sort_by_key(|timing| std::cmp::Reverse(timing.total()));
+    }
+    let report = match format {
+        Format::Table => timing::table(&timings, io::stdout().is_terminal()),
+        Format::Json => timing::json(&timings),
+        Format::Csv => timing::csv(&timings).unwrap_or_else(|e| {
+            eprintln!("could not write the CSV report: {}", e);
+            std::process::exit(1);
+        }),
+    };
+    print!("{}", report);
+}
+
+fn describe_error(day: &Day, e: &Error) -> String {
+    match e {
+        // Parse errors already name the day they come from
+        Error::Parse(_) => e.to_string(),
+        _ => format!("{}: {}", day.name, e),
+    }
+}
+
+/// Plays the simulation of `day` in the terminal when `fps` is given, and saves its frames
+/// when `export` is.
+fn animate_day(
+    day: &Day,
+    input: Option<&str>,
+    fps: Option<u32>,
+    export: Option<Export>,
+) -> Result<()> {
+    let Some(frames) = day.animate else {
+        let animated = days::DAYS.iter().filter(|d| d.animate.is_some());
+        let names = animated.map(|d| d.name).collect::<Vec<_>>().join(", ");
+        eprintln!("{} has no animation, try one of {}", day.name, names);
+        std::process::exit(1);
+    };
+    let input = InputSource::new(day.number, input).read()?;
+    if let Some(export) = &export {
+        fs::create_dir_all(export.dir).map_err(|e| Error::file(export.dir, e))?;
+    }
+    let mut player = fps.map(|fps| Player::new(io::stdout().lock(), fps));
+    let mut gif = None;
+    for (index, frame) in frames(&input)?.enumerate() {
+        if let Some(export) = &export {
+            match export.format {
+                ExportFormat::Txt => animation::save_text(&frame, export.dir, index)?,
+                ExportFormat::Png => {
+                    let path = export.dir.join(format!("frame{:04}.png", index));
+                    export::save_png(&frame, &path, export.scale)?
+                }
+                ExportFormat::Gif => {
+                    let gif = match &mut gif {
+                        Some(gif) => gif,
+                        None => {
+                            let path = export.dir.join(format!("{}.gif", day.name));
+                            let writer =
+                                GifWriter::create(&path, &frame, export.scale, export.fps)?;
+                            gif.insert(writer)
+                        }
+                    };
+                    gif.write(&frame)?
+                }
+            }
+        }
+        if let Some(player) = &mut player {
+            if player.show(&frame).is_err() {
+                // Nobody is watching anymore
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reports the results of the days, failures on stderr without stopping the batch.
+fn report_days<'a>(
+    results: impl IntoIterator<Item = (&'a Day, std::result::Result<Answers, String>)>,
+    report: Report,
+) {
+    let mut failed = false;
+    let mut timings = vec![];
+    for (day, result) in results {
+        match result {
+            Ok(answers) => match report {
+                Report::Answers => print_answers(day, answers),
+                Report::Timings { .. } => timings.push(Timing::new(day.name, &answers)),
+            },
+            Err(message) => {
+                eprintln!("{}", message);
+                failed = true;
+            }
+        }
+    }
+    if let Report::Timings { format, sort } = report {
+        print_timings(timings, format, sort);
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Runs the days one after the other.
+fn run_days<'a>(
+    days: impl IntoIterator<Item = &'a Day>,
+    part: Option<u8>,
+    input: Option<&str>,
+    report: Report,
+) {
+    let results = days.into_iter().map(|day| {
+        let result = run_day(day, part, input).map_err(|e| describe_error(day, &e));
+        (day, result)
+    });
+    report_days(results, report);
+}
+
+/// Runs the days concurrently on their default inputs, a day that panics or runs out of
+/// time failing on its own.
+fn run_days_parallel(
+    days: &'static [Day],
+    part: Option<u8>,
+    timeout: Option<Duration>,
+    report: Report,
+) {
+    let outcomes = batch::run_ordered(days.iter().collect(), timeout, move |day| {
+        run_day(day, part, None)
+    });
+    let results = days.iter().zip(outcomes).map(|(day, outcome)| {
+        let result = match outcome {
+            BatchOutcome::Done(result) => result.map_err(|e| describe_error(day, &e)),
+            BatchOutcome::Panicked(message) => Err(format!("{}: panicked: {}", day.name, message)),
+            BatchOutcome::TimedOut(timeout) => Err(format!(
+                "{}: timed out after {}",
+                day.name,
+                format_duration(timeout)
+            )),
+        };
+        (day, result)
+    });
+    // Days that timed out may still be running, until the process exits
+    report_days(results, report);
+}
+
+/// One line of the `verify` table: the day, then the status and time of each part.
+fn format_row(day: &str, parts: [(&str, String); 2]) -> String {
+    let [(status1, time1), (status2, time2)] = parts;
+    let row = format!(
+        "{:<14} {:<7} {:>10}   {:<7} {:>10}",
+        day, status1, time1, status2, time2
+    );
+    row.trim_end().to_string()
+}
+
+/// Runs every day against its input and prints a pass/fail table with timings.
+fn verify<'a>(days: impl IntoIterator<Item = &'a Day>, sheet: &AnswerSheet) {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    let mut details = vec![];
+    let header = [("part1", "time".to_string()), ("part2", "time".to_string())];
+    println!("{}", format_row("day", header));
+    for day in days {
+        let answers = InputSource::new(day.number, None)
+            .read()
+            .map_err(Error::from)
+            .and_then(|input| day.solve(&input, None));
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(e) => {
+                let error = ("error", String::new());
+                println!("{}", format_row(day.name, [error.clone(), error]));
+                details.push(describe_error(day, &e));
+                failed += 2;
+                continue;
+            }
+        };
+        let parts = [(1, answers.part1), (2, answers.part2)].map(|(index, answer)| {
+            let Answer { value, time } = answer.expect("both parts are run");
+            let status = match sheet.check(day.name, index, &value) {
+                Verdict::Pass => {
+                    passed += 1;
+                    "pass"
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    details.push(format!(
+                        "{} part{}: expected {:?}, got {:?}",
+                        day.name, index, expected, value
+                    ));
+                    "FAIL"
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    "-"
+                }
+            };
+            (status, format_duration(time))
+        });
+        println!("{}", format_row(day.name, parts));
+    }
+    println!();
+    for detail in &details {
+        println!("{}", detail);
+    }
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Downloads the input of `day` into the input directory.
+fn fetch(day: &Day, config: &Path) -> Result<Fetched> {
+    let dir = input_dir();
+    let path = input_path(day.number, &dir);
+    if path.exists() {
+        // Cached inputs need no session token
+        return Ok(Fetched::Cached(path));
+    }
+    let session = Config::load(config)?.session()?;
+    client::fetch_input(&AocClient::with_session(session, &dir), day.number, &dir)
+}
+
+/// Computes the answer of `part` and submits it, unless the log already judged it.
+/// Returns whether the answer is correct.
+fn submit(
+    day: &Day,
+    part: u8,
+    input: Option<&str>,
+    config: &Path,
+    submissions: &Path,
+) -> Result<bool> {
+    let answers = run_day(day, Some(part), input)?;
+    let answer = [answers.part1, answers.part2][part as usize - 1]
+        .take()
+        .expect("the requested part is run")
+        .value;
+    let answer = answer.trim();
+    if answer.contains('\n') {
+        eprintln!("{} part{}:\n{}", day.name, part, answer);
+        eprintln!("This answer is a drawing: submit the letters it shows by hand");
+        return Ok(false);
+    }
+    let mut log = SubmissionLog::load(submissions)?;
+    match log.check(day.name, part, answer) {
+        Known::Correct => {
+            println!("{} part{}: {} was already accepted", day.name, part, answer);
+            return Ok(true);
+        }
+        Known::Wrong { reason } => {
+            eprintln!(
+                "{} part{}: not submitting {}, {}",
+                day.name, part, answer, reason
+            );
+            return Ok(false);
+        }
+        Known::New => (),
+    }
+    let session = Config::load(config)?.session()?;
+    let client = AocClient::with_session(session, &input_dir());
+    println!("{} part{}: submitting {}", day.name, part, answer);
+    let Feedback {
+        outcome,
+        wait,
+        message,
+    } = client.submit(day.number, part, answer)?;
+    if outcome.is_verdict() {
+        log.record(day.name, part, answer, outcome);
+        log.save(submissions)?;
+    }
+    println!("{}", message);
+    if let Some(wait) = wait {
+        println!("Next submission allowed in {}s", wait.as_secs());
+    }
+    Ok(outcome == Outcome::Correct)
+}
+
+/// Looks up the day named on the command line, exiting when it does not exist.
+fn find_day(query: &str) -> &'static Day {
+    days::find(query).unwrap_or_else(|| {
+        eprintln!("Unknown day: {}", query);
+        std::process::exit(1);
+    })
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            time,
+            format,
+            sort,
+            parallel,
+            timeout,
+            trace,
+            no_progress,
+            animate,
+            fps,
+            export,
+            export_format,
+            scale,
+        } => {
+            trace::set_level(trace.unwrap_or(Level::Off));
+            // Bars would skew the timings and get in the way of the traces
+            progress::set_enabled(!(no_progress || time || parallel || trace.is_some()));
+            if animate || export.is_some() {
+                let day = find_day(&day.expect("day is required without --all"));
+                let export = export.as_deref().map(|dir| Export {
+                    dir,
+                    format: export_format,
+                    scale: scale as usize,
+                    fps,
+                });
+                let play = animate.then_some(fps);
+                if let Err(e) = animate_day(day, input.as_deref(), play, export) {
+                    eprintln!("{}", describe_error(day, &e));
+                    std::process::exit(1);
+                }
+                return;
+            }
+            let report = if time {
+                Report::Timings { format, sort }
+            } else {
+                Report::Answers
+            };
+            if parallel {
+                let timeout = timeout.map(Duration::from_secs);
+                run_days_parallel(days::DAYS, part, timeout, report);
+            } else if all {
+                run_days(days::DAYS, part, None, report);
+            } else {
+                let day = find_day(&day.expect("day or --all is required"));
+                run_days([day], part, input.as_deref(), report);
+            }
+        }
+        Command::Verify { day, answers } => {
+            let sheet = AnswerSheet::load(&answers).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            });
+            match day {
+                Some(query) => verify([find_day(&query)], &sheet),
+                None => verify(days::DAYS, &sheet),
+            }
+        }
+        Command::Fetch { day, config } => match fetch(find_day(&day), &config) {
+            Ok(Fetched::Downloaded(path)) => println!("Downloaded {}", path.display()),
+            Ok(Fetched::Cached(path)) => println!("Already cached in {}", path.display()),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
+        Command::Submit {
+            day,
+            part,
+            input,
+            config,
+            submissions,
+        } => {
+            let day = find_day(&day);
+            match submit(day, part, input.as_deref(), &config, &submissions) {
+                Ok(true) => (),
+                Ok(false) => std::process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", describe_error(day, &e));
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::New { day } => match scaffold::new_day(Path::new("."), day) {
+            Ok(written) => {
+                for path in written {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
+        Command::List => {
+            for day in days::DAYS {
+                println!("{:>2}  {}", day.number, day.name);
+            }
+        }
+    }
+}
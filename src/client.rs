@@ -0,0 +1,535 @@
+//! Talking to adventofcode.com with the user's session token: downloading inputs and
+//! submitting answers.
+//!
+//! Requests go through the [`HttpClient`] trait, so the website can be replaced by a local
+//! stub in tests. They are spaced by at least [`MIN_INTERVAL`], remembered across runs in
+//! a stamp file, to stay polite with the servers.
+
+use std::{
+    env, error, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::{
+    error::{Error, Result},
+    input::input_path,
+};
+
+pub const YEAR: u16 = 2022;
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable holding the session token, taking precedence over the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Default config file, kept out of git since it holds the session token.
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Minimum delay between two requests to the website.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+/// Name of the stamp file recording the time of the last request.
+const STAMP_FILE: &str = ".last-request";
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), " (", env!("CARGO_PKG_AUTHORS"), ")");
+
+/// Why a request could not be sent or its response read.
+pub type TransportError = Box<dyn error::Error + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// Fields sent url-encoded in the body of `Post` requests
+    pub form: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends HTTP requests. Error statuses are responses, not errors.
+pub trait HttpClient {
+    fn send(&self, request: &Request) -> std::result::Result<Response, TransportError>;
+}
+
+/// The real HTTP client.
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+        UreqClient { agent }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn send(&self, request: &Request) -> std::result::Result<Response, TransportError> {
+        let mut response = match request.method {
+            Method::Get => {
+                let mut builder = self.agent.get(&request.url);
+                for (name, value) in &request.headers {
+                    builder = builder.header(name, value);
+                }
+                builder.call()?
+            }
+            Method::Post => {
+                let mut builder = self.agent.post(&request.url);
+                for (name, value) in &request.headers {
+                    builder = builder.header(name, value);
+                }
+                builder.send_form(request.form.iter().map(|(k, v)| (k.as_str(), v.as_str())))?
+            }
+        };
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string()?,
+        })
+    }
+}
+
+/// Settings read from the config file:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+}
+
+impl Config {
+    /// Reads the config file, a missing file being an empty config.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::file(path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(Error::file(path, e)),
+        }
+    }
+
+    /// The session token: `$AOC_SESSION` if set, otherwise the one of the config file.
+    pub fn session(&self) -> Result<String> {
+        env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| {
+                let message = format!(
+                    "no session token, set {} or `session` in {}",
+                    SESSION_VAR, CONFIG_FILE
+                );
+                Error::remote(BASE_URL, message)
+            })
+    }
+}
+
+/// Spaces requests by `interval`, using the modification time of a stamp file.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: PathBuf, interval: Duration) -> Self {
+        Throttle { stamp, interval }
+    }
+
+    /// How long to wait at `now` before the next request is allowed.
+    pub fn delay(&self, now: SystemTime) -> Duration {
+        fs::metadata(&self.stamp)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|last| now.duration_since(last).ok())
+            .map_or(Duration::ZERO, |elapsed| {
+                self.interval.saturating_sub(elapsed)
+            })
+    }
+
+    /// Waits for the next request to be allowed, and records it.
+    pub fn wait(&self) -> Result<()> {
+        thread::sleep(self.delay(SystemTime::now()));
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir).map_err(|e| Error::file(dir, e))?;
+        }
+        fs::write(&self.stamp, "").map_err(|e| Error::file(&self.stamp, e))
+    }
+}
+
+/// A client of the Advent of Code website, authenticated by a session token.
+pub struct AocClient<C> {
+    http: C,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl<C: HttpClient> AocClient<C> {
+    pub fn new(http: C, base_url: &str, session: String, throttle: Throttle) -> Self {
+        AocClient {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle,
+        }
+    }
+
+    /// Sends an authenticated request to `path`, failing on anything but a success.
+    pub fn send(&self, method: Method, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = Request {
+            method,
+            url: url.clone(),
+            headers: vec![
+                ("Cookie".into(), format!("session={}", self.session)),
+                ("User-Agent".into(), USER_AGENT.into()),
+            ],
+            form: form
+                .iter()
+                .map(|&(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        };
+        self.throttle.wait()?;
+        let response = self
+            .http
+            .send(&request)
+            .map_err(|e| Error::remote(&url, e))?;
+        if !(200..300).contains(&response.status) {
+            let message = format!("HTTP {}: {}", response.status, response.body.trim());
+            return Err(Error::remote(&url, message));
+        }
+        Ok(response.body)
+    }
+
+    pub fn input(&self, day: u8) -> Result<String> {
+        self.send(Method::Get, &format!("/{}/day/{}/input", YEAR, day), &[])
+    }
+
+    /// Submits `answer` to `part` of `day`, and reads the website's verdict.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Feedback> {
+        let path = format!("/{}/day/{}/answer", YEAR, day);
+        let level = part.to_string();
+        let page = self.send(
+            Method::Post,
+            &path,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        Feedback::parse(&page).ok_or_else(|| {
+            let message = format!("unexpected response: {}", article_text(&page));
+            Error::remote(format!("{}{}", self.base_url, path), message)
+        })
+    }
+}
+
+impl AocClient<UreqClient> {
+    /// A client of the real website, throttled through a stamp file in `dir`.
+    pub fn with_session(session: String, dir: &Path) -> Self {
+        let throttle = Throttle::new(dir.join(STAMP_FILE), MIN_INTERVAL);
+        AocClient::new(UreqClient::default(), BASE_URL, session, throttle)
+    }
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint
+    Wrong,
+    /// Not judged: the previous answer was submitted too recently
+    TooSoon,
+    /// Not judged: the part is already solved, or the previous one is not
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Whether the answer was judged, and can be remembered.
+    pub fn is_verdict(self) -> bool {
+        !matches!(self, Outcome::TooSoon | Outcome::WrongLevel)
+    }
+}
+
+/// The response to a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feedback {
+    pub outcome: Outcome,
+    /// How long to wait before submitting again, when the website says so
+    pub wait: Option<Duration>,
+    /// The text of the response, without its markup
+    pub message: String,
+}
+
+impl Feedback {
+    /// Reads the response page of a submission, `None` when it is not one.
+    pub fn parse(page: &str) -> Option<Feedback> {
+        let message = article_text(page);
+        let outcome = if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("not the right answer") {
+            if message.contains("too high") {
+                Outcome::TooHigh
+            } else if message.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("answer too recently") {
+            Outcome::TooSoon
+        } else if message.contains("solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            return None;
+        };
+        Some(Feedback {
+            outcome,
+            wait: parse_wait(&message),
+            message,
+        })
+    }
+}
+
+/// The delay in "You have 1m 30s left to wait", or "please wait 5 minutes".
+fn parse_wait(message: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(message) {
+        let minutes = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+    let penalty = Regex::new(r"wait (one|\d+) minutes?").unwrap();
+    let minutes = match &penalty.captures(message)?[1] {
+        "one" => 1,
+        n => n.parse().unwrap(),
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// The text of the `<article>` of a page, where the website puts its messages.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| {
+            article.split_once('>').map_or(article, |(_, a)| a)
+        });
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(article, "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, nothing was downloaded
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of `day` into `dir`, unless it is already there.
+pub fn fetch_input<C: HttpClient>(client: &AocClient<C>, day: u8, dir: &Path) -> Result<Fetched> {
+    let path = input_path(day, dir);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.input(day)?;
+    fs::create_dir_all(dir).map_err(|e| Error::file(dir, e))?;
+    // Written aside first so that an interrupted download never looks cached
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(|e| Error::file(&partial, e))?;
+    fs::rename(&partial, &path).map_err(|e| Error::file(&path, e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+
+    /// A local HTTP server answering every request with the same response, and recording
+    /// the requests it receives as `(request line, headers and body)`.
+    pub(crate) struct StubServer {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl StubServer {
+        pub(crate) fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    let (mut rest, mut length) = (String::new(), 0);
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                        if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                            length = value.trim().parse().unwrap();
+                        }
+                        rest.push_str(&line);
+                    }
+                    let mut content = vec![0; length];
+                    reader.read_exact(&mut content).unwrap();
+                    rest.push_str(&String::from_utf8(content).unwrap());
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push((request_line.trim().to_string(), rest));
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+            StubServer { url, requests }
+        }
+
+        pub(crate) fn client(&self, dir: &Path) -> AocClient<UreqClient> {
+            let throttle = Throttle::new(dir.join(STAMP_FILE), Duration::ZERO);
+            AocClient::new(UreqClient::default(), &self.url, "token".into(), throttle)
+        }
+    }
+
+    /// A fresh directory under the system's temporary directory.
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc22-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let server = StubServer::start(200, "1000\n2000\n");
+        let dir = temp_dir("fetch");
+        let client = server.client(&dir);
+        let path = input_path(1, &dir);
+        assert_eq!(
+            fetch_input(&client, 1, &dir).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            fetch_input(&client, 1, &dir).unwrap(),
+            Fetched::Cached(path)
+        );
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, "GET /2022/day/1/input HTTP/1.1");
+        assert!(requests[0].1.contains("session=token"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let server = StubServer::start(400, "Please log in\n");
+        let dir = temp_dir("fetch-error");
+        let error = fetch_input(&server.client(&dir), 2, &dir).unwrap_err();
+        assert!(error.to_string().ends_with("HTTP 400: Please log in"));
+        assert!(!input_path(2, &dir).exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = "<html><main><article><p>That's not the right answer; your answer is too \
+                    high. Please wait one minute before trying again. \
+                    <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main></html>";
+        let server = StubServer::start(200, page);
+        let dir = temp_dir("submit");
+        let feedback = server.client(&dir).submit(1, 2, "12345").unwrap();
+        assert_eq!(feedback.outcome, Outcome::TooHigh);
+        assert_eq!(feedback.wait, Some(Duration::from_secs(60)));
+        assert!(feedback
+            .message
+            .ends_with("trying again. [Return to Day 1]"));
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests[0].0, "POST /2022/day/1/answer HTTP/1.1");
+        assert!(requests[0].1.ends_with("level=2&answer=12345"));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_feedback() {
+        let outcome = |text: &str| {
+            let page = format!("<article><p>{}</p></article>", text);
+            Feedback::parse(&page).map(|f| (f.outcome, f.wait.map(|w| w.as_secs())))
+        };
+        assert_eq!(
+            outcome("That's the right answer! You are one gold star closer."),
+            Some((Outcome::Correct, None))
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low."),
+            Some((Outcome::TooLow, None))
+        );
+        assert_eq!(
+            outcome("That's not the right answer. Please wait 5 minutes before trying again."),
+            Some((Outcome::Wrong, Some(300)))
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently. You have 1m 30s left to wait."),
+            Some((Outcome::TooSoon, Some(90)))
+        );
+        assert_eq!(
+            outcome("You have 42s left to wait. You gave an answer too recently."),
+            Some((Outcome::TooSoon, Some(42)))
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level."),
+            Some((Outcome::WrongLevel, None))
+        );
+        assert_eq!(outcome("Puzzle inputs differ by user."), None);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let dir = temp_dir("throttle");
+        let throttle = Throttle::new(dir.join(STAMP_FILE), Duration::from_secs(5));
+        let now = SystemTime::now();
+        assert_eq!(throttle.delay(now), Duration::ZERO);
+        throttle.wait().unwrap();
+        let delay = throttle.delay(SystemTime::now() + Duration::from_secs(2));
+        assert!(delay > Duration::from_secs(2) && delay <= Duration::from_secs(3));
+        assert_eq!(
+            throttle.delay(SystemTime::now() + Duration::from_secs(6)),
+            Duration::ZERO
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    groups::{parse_groups, GroupStats},
+    solution::{Result, Solution},
+    top_k::TopK,
+    trace,
+};
+
+/// Number of elves carrying the most calories that the answers need.
+const TOP: usize = 3;
+/// Width of the calorie ranges of the histogram.
+const HISTOGRAM_BUCKET: u64 = 5000;
+
+/// The calories carried by the elves, summarised as they are read.
+#[derive(Debug, Clone)]
+pub struct Calories {
+    /// The largest totals, from the largest down
+    pub top: Vec<u64>,
+    /// Statistics of the total of every elf
+    pub stats: GroupStats,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Calories;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut top = TopK::new(TOP);
+        let mut stats = GroupStats::new(HISTOGRAM_BUCKET);
+        for elf in parse_groups(Self::DAY, input, |line| Ok(line.parse::<u64>()?)) {
+            let total = elf?.into_iter().sum();
+            top.push(total);
+            stats.add(total);
+        }
+        trace!(Summary, "Calories per elf: {}", stats);
+        Ok(Calories {
+            top: top.into_sorted_vec(),
+            stats,
+        })
+    }
+
+    fn part1(calories: &Self::Input) -> u64 {
+        calories.top.first().copied().unwrap_or(0)
+    }
+
+    fn part2(calories: &Self::Input) -> u64 {
+        calories.top.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{error::Error, solution::get_example};
+
+    #[test]
+    fn test_example() {
+        let input = Day01::parse(&get_example(Day01::DAY)).unwrap();
+        assert_eq!(Day01::part1(&input), 24000);
+        assert_eq!(Day01::part2(&input), 45000);
+    }
+
+    #[test]
+    fn test_stats() {
+        let input = Day01::parse(&get_example(Day01::DAY)).unwrap();
+        let stats = &input.stats;
+        assert_eq!(stats.count(), 5);
+        assert_eq!((stats.min(), stats.max()), (Some(4000), Some(24000)));
+        assert_eq!(stats.mean(), Some(11000.0));
+        let counts = stats.histogram().into_iter().map(|(_, count)| count);
+        assert_eq!(counts.collect::<Vec<_>>(), [1, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_invalid_calories() {
+        let Err(Error::Parse(error)) = Day01::parse("1\nx") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((error.line, error.text.as_str()), (2, "x"));
+    }
+}
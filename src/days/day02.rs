@@ -0,0 +1,399 @@
+use std::{collections::HashSet, str::FromStr};
+
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::{
+    error::parse_lines,
+    solution::{Result, Solution},
+    trace,
+};
+
+/// The rules of the puzzle.
+pub const CLASSIC: &str = r#"
+name = "Rock Paper Scissors"
+shapes = ["Rock", "Paper", "Scissors"]
+"#;
+
+/// Each shape beats two others: scissors cut paper, paper covers rock, rock crushes lizard,
+/// lizard poisons Spock, Spock smashes scissors, and so on.
+pub const LIZARD_SPOCK: &str = r#"
+name = "Rock Paper Scissors Lizard Spock"
+shapes = ["Rock", "Spock", "Paper", "Lizard", "Scissors"]
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn score(self) -> u64 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// A cyclic game, described in TOML by its `name` and its `shapes`. Each shape beats the
+/// half of the others listed just before it, wrapping around, and loses to the other half,
+/// which takes an odd number of shapes. A shape is worth its position in the list, from 1.
+///
+/// In a strategy guide, their shapes are coded from `A` on, and ours with as many letters
+/// ending at `Z`: `X`, `Y` and `Z` for three shapes.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawRuleSet")]
+pub struct RuleSet {
+    pub name: String,
+    shapes: Vec<String>,
+}
+
+/// A rule set as written, before its shapes are checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRuleSet {
+    name: String,
+    shapes: Vec<String>,
+}
+
+impl TryFrom<RawRuleSet> for RuleSet {
+    type Error = String;
+
+    fn try_from(raw: RawRuleSet) -> std::result::Result<Self, Self::Error> {
+        let count = raw.shapes.len();
+        if count < 3 || count.is_multiple_of(2) {
+            return Err(format!(
+                "expected an odd number of shapes, from 3, got {}",
+                count
+            ));
+        }
+        if count > 13 {
+            return Err(format!(
+                "{} shapes are too many to code with letters",
+                count
+            ));
+        }
+        if raw.shapes.iter().collect::<HashSet<_>>().len() < count {
+            return Err("shape names must be distinct".to_string());
+        }
+        Ok(RuleSet {
+            name: raw.name,
+            shapes: raw.shapes,
+        })
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|e| e.to_string())
+    }
+}
+
+impl RuleSet {
+    pub fn classic() -> Self {
+        CLASSIC.parse().expect("the classic rules are valid")
+    }
+
+    /// Number of shapes.
+    pub fn shape_count(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn shape_name(&self, shape: usize) -> &str {
+        &self.shapes[shape]
+    }
+
+    /// How `ours` fares against `theirs`.
+    pub fn outcome(&self, theirs: usize, ours: usize) -> Outcome {
+        let steps = (ours + self.shape_count() - theirs) % self.shape_count();
+        if steps == 0 {
+            Outcome::Draw
+        } else if steps <= self.shape_count() / 2 {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    pub fn score(&self, theirs: usize, ours: usize) -> u64 {
+        ours as u64 + 1 + self.outcome(theirs, ours).score()
+    }
+
+    /// The shape `offset` steps after `shape` along the cycle.
+    fn shift(&self, shape: usize, offset: isize) -> usize {
+        (shape as isize + offset).rem_euclid(self.shape_count() as isize) as usize
+    }
+
+    fn their_letters(&self) -> impl Iterator<Item = char> {
+        ('A'..='Z').take(self.shape_count())
+    }
+
+    fn our_letters(&self) -> impl Iterator<Item = char> {
+        ('A'..='Z').skip(26 - self.shape_count())
+    }
+
+    /// The index of `code` among `letters`.
+    fn decode(
+        letters: impl Iterator<Item = char>,
+        code: &str,
+    ) -> std::result::Result<usize, String> {
+        let mut chars = code.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => letters.into_iter().position(|l| l == c),
+            _ => None,
+        }
+        .ok_or_else(|| format!("unknown code {:?}", code))
+    }
+}
+
+/// The most shapes for which [`Interpretation::all`] tries every assignment of them.
+pub const MAX_PERMUTED_SHAPES: usize = 5;
+
+/// How the second column of the strategy guide is read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The `i`th code is the shape at index `i`
+    Shapes(Vec<usize>),
+    /// The code is how far our shape is from theirs along the cycle, the middle code
+    /// meaning the same shape: with three shapes, `X`, `Y` and `Z` mean lose, draw and win
+    Relative,
+}
+
+impl Interpretation {
+    /// The puzzle's first reading, each code being the shape at the same position.
+    pub fn in_order(rules: &RuleSet) -> Self {
+        Interpretation::Shapes((0..rules.shape_count()).collect())
+    }
+
+    /// Every reading worth trying, then the relative one. Up to `MAX_PERMUTED_SHAPES`
+    /// shapes, that is every assignment of shapes to the codes; past that, their `n!`
+    /// would be too many, and only the codes in order shifted along the cycle are tried.
+    pub fn all(rules: &RuleSet) -> Vec<Self> {
+        let count = rules.shape_count();
+        let shapes: Vec<Vec<usize>> = if count <= MAX_PERMUTED_SHAPES {
+            (0..count).permutations(count).collect()
+        } else {
+            (0..count)
+                .map(|offset| (0..count).map(|code| (code + offset) % count).collect())
+                .collect()
+        };
+        shapes
+            .into_iter()
+            .map(Interpretation::Shapes)
+            .chain([Interpretation::Relative])
+            .collect()
+    }
+
+    /// Our shape for `code` against `theirs`.
+    pub fn play(&self, rules: &RuleSet, theirs: usize, code: usize) -> usize {
+        match self {
+            Interpretation::Shapes(shapes) => shapes[code],
+            Interpretation::Relative => {
+                rules.shift(theirs, code as isize - (rules.shape_count() / 2) as isize)
+            }
+        }
+    }
+
+    /// What each code means, e.g. `X=Rock Y=Paper Z=Scissors`.
+    pub fn describe(&self, rules: &RuleSet) -> String {
+        let meanings = (0..rules.shape_count()).map(|code| match self {
+            Interpretation::Shapes(shapes) => rules.shape_name(shapes[code]).to_string(),
+            Interpretation::Relative => {
+                format!("{:+}", code as isize - (rules.shape_count() / 2) as isize)
+            }
+        });
+        rules
+            .our_letters()
+            .zip(meanings)
+            .map(|(letter, meaning)| format!("{}={}", letter, meaning))
+            .join(" ")
+    }
+}
+
+/// The scores of a strategy guide followed with one interpretation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    pub interpretation: Interpretation,
+    /// Our score in each round
+    pub scores: Vec<u64>,
+}
+
+impl Evaluation {
+    pub fn total(&self) -> u64 {
+        self.scores.iter().sum()
+    }
+
+    /// The score to expect from a round.
+    pub fn mean(&self) -> f64 {
+        self.total() as f64 / self.scores.len().max(1) as f64
+    }
+}
+
+/// A strategy guide: their shape and our code in each round.
+#[derive(Debug, Clone)]
+pub struct Guide {
+    pub rules: RuleSet,
+    pub rounds: Vec<(usize, usize)>,
+}
+
+impl Guide {
+    pub fn parse(rules: RuleSet, input: &str) -> Result<Self> {
+        let rounds = parse_lines(Day02::DAY, input, |line| {
+            let (theirs, ours) = line.split_once(' ').ok_or("missing column")?;
+            Ok((
+                RuleSet::decode(rules.their_letters(), theirs)?,
+                RuleSet::decode(rules.our_letters(), ours)?,
+            ))
+        })?;
+        Ok(Guide { rules, rounds })
+    }
+
+    pub fn evaluate(&self, interpretation: Interpretation) -> Evaluation {
+        let scores = self
+            .rounds
+            .iter()
+            .map(|&(theirs, code)| {
+                let ours = interpretation.play(&self.rules, theirs, code);
+                self.rules.score(theirs, ours)
+            })
+            .collect();
+        Evaluation {
+            interpretation,
+            scores,
+        }
+    }
+
+    /// Every interpretation of the guide, from the best total score to the worst.
+    pub fn alternatives(&self) -> Vec<Evaluation> {
+        Interpretation::all(&self.rules)
+            .into_iter()
+            .map(|interpretation| self.evaluate(interpretation))
+            .sorted_by_key(|evaluation| std::cmp::Reverse(evaluation.total()))
+            .collect()
+    }
+
+    /// The total score of `interpretation`, tracing the score of each round.
+    fn score(&self, interpretation: Interpretation) -> u64 {
+        let evaluation = self.evaluate(interpretation);
+        for (index, score) in evaluation.scores.iter().enumerate() {
+            trace!(Steps, "Round {}: {}", index + 1, score);
+        }
+        evaluation.total()
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Guide;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Guide::parse(RuleSet::classic(), input)
+    }
+
+    fn part1(guide: &Self::Input) -> u64 {
+        guide.score(Interpretation::in_order(&guide.rules))
+    }
+
+    fn part2(guide: &Self::Input) -> u64 {
+        if trace::enabled(trace::Level::Summary) {
+            for evaluation in guide.alternatives() {
+                let description = evaluation.interpretation.describe(&guide.rules);
+                trace!(
+                    Summary,
+                    "{}: {} ({:.2} per round)",
+                    description,
+                    evaluation.total(),
+                    evaluation.mean()
+                );
+            }
+        }
+        guide.score(Interpretation::Relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day02::parse(&get_example(Day02::DAY)).unwrap();
+        assert_eq!(Day02::part1(&input), 15);
+        assert_eq!(Day02::part2(&input), 12);
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules: RuleSet = LIZARD_SPOCK.parse().unwrap();
+        let shape = |name| (0..5).find(|&s| rules.shape_name(s) == name).unwrap();
+        let beats = |a, b| rules.outcome(shape(b), shape(a)) == Outcome::Win;
+        assert!(beats("Scissors", "Paper") && beats("Paper", "Rock"));
+        assert!(beats("Rock", "Lizard") && beats("Lizard", "Spock"));
+        assert!(beats("Spock", "Scissors") && beats("Scissors", "Lizard"));
+        assert!(beats("Lizard", "Paper") && beats("Paper", "Spock"));
+        assert!(beats("Spock", "Rock") && beats("Rock", "Scissors"));
+        assert!(!beats("Rock", "Paper") && !beats("Rock", "Rock"));
+
+        assert!("shapes = [\"a\", \"b\"]\nname = \"\""
+            .parse::<RuleSet>()
+            .is_err());
+        assert!("shapes = [\"a\", \"b\", \"a\"]\nname = \"\""
+            .parse::<RuleSet>()
+            .is_err());
+        // Deserialising checks the shapes too
+        assert!(toml::from_str::<RuleSet>("shapes = [\"a\", \"b\"]\nname = \"\"").is_err());
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let guide = Day02::parse(&get_example(Day02::DAY)).unwrap();
+        let alternatives = guide.alternatives();
+        assert_eq!(alternatives.len(), 7);
+        let total = |interpretation| {
+            let evaluation = alternatives
+                .iter()
+                .find(|e| e.interpretation == interpretation)
+                .unwrap();
+            evaluation.total()
+        };
+        assert_eq!(total(Interpretation::in_order(&guide.rules)), 15);
+        assert_eq!(total(Interpretation::Relative), 12);
+        assert!(alternatives
+            .windows(2)
+            .all(|w| w[0].total() >= w[1].total()));
+        assert_eq!(guide.evaluate(Interpretation::Relative).scores, [4, 1, 7]);
+        assert_eq!(
+            Interpretation::Relative.describe(&guide.rules),
+            "X=-1 Y=+0 Z=+1"
+        );
+    }
+
+    #[test]
+    fn test_lizard_spock_guide() {
+        let rules: RuleSet = LIZARD_SPOCK.parse().unwrap();
+        let guide = Guide::parse(rules, "A V\nE Z\nC X").unwrap();
+        // Rock against Rock, Scissors against Scissors, Paper against Paper
+        let evaluation = guide.evaluate(Interpretation::in_order(&guide.rules));
+        assert_eq!(evaluation.scores, [4, 8, 6]);
+        assert_eq!(guide.alternatives().len(), 121);
+        assert!(Guide::parse(guide.rules.clone(), "F X").is_err());
+        // Past the cutoff, as many readings as shapes, and the relative one
+        let shapes = (1..=13).map(|i| format!("\"{}\"", i)).join(", ");
+        let rules: RuleSet = format!("name = \"13\"\nshapes = [{}]", shapes)
+            .parse()
+            .unwrap();
+        assert_eq!(Interpretation::all(&rules).len(), 14);
+    }
+}
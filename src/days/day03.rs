@@ -0,0 +1,327 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    error::{ParseError, Result},
+    solution::{Attempt, Solution},
+};
+
+fn get_item_priority(item: char) -> u32 {
+    if item.is_uppercase() {
+        27 + item as u32 - 'A' as u32
+    } else {
+        1 + item as u32 - 'a' as u32
+    }
+}
+
+/// The item of the given priority, from 1 to 52.
+fn get_item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        _ => (b'A' + priority as u8 - 27) as char,
+    }
+}
+
+/// A set of items, the bit `p - 1` standing for the item of priority `p`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Every item.
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    /// Adds a letter, which the caller checks.
+    fn insert(&mut self, item: char) {
+        self.0 |= 1 << (get_item_priority(item) - 1);
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        item.is_ascii_alphabetic() && self.0 & (1 << (get_item_priority(item) - 1)) != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items, by increasing priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        (1..=52)
+            .filter(move |p| self.0 & (1 << (p - 1)) != 0)
+            .map(get_item)
+    }
+
+    /// The item, if it is the only one.
+    pub fn single(self) -> Option<char> {
+        (self.len() == 1).then(|| get_item(self.0.trailing_zeros() + 1))
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        self.intersection(other)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        self.union(other)
+    }
+}
+
+impl FromStr for ItemSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut set = ItemSet::default();
+        for item in s.chars() {
+            if !item.is_ascii_alphabetic() {
+                return Err(format!("invalid item {:?}", item));
+            }
+            set.insert(item);
+        }
+        Ok(set)
+    }
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.items().collect::<String>())
+    }
+}
+
+/// The items of a rucksack, by compartment, with its line in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    pub line: usize,
+    pub text: String,
+    pub left: ItemSet,
+    pub right: ItemSet,
+}
+
+impl Rucksack {
+    pub fn items(&self) -> ItemSet {
+        self.left | self.right
+    }
+}
+
+/// Parses the rucksacks as they are read.
+pub fn rucksacks(input: &str) -> impl Iterator<Item = Result<Rucksack>> + '_ {
+    input.lines().enumerate().map(|(index, line)| {
+        let error = |message: String| ParseError::new(Day03::DAY, index + 1, line, message);
+        // Checked first, so that the line is split between two ASCII items
+        if let Some(item) = line.chars().find(|c| !c.is_ascii_alphabetic()) {
+            return Err(error(format!("invalid item {:?}", item)).into());
+        }
+        if line.len() % 2 != 0 {
+            return Err(error("odd number of items".to_string()).into());
+        }
+        let (left, right) = line.split_at(line.len() / 2);
+        Ok(Rucksack {
+            line: index + 1,
+            text: line.to_string(),
+            left: left.parse().map_err(error)?,
+            right: right.parse().map_err(error)?,
+        })
+    })
+}
+
+/// The item common to a group of rucksacks, and their lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badge {
+    pub item: char,
+    pub lines: Vec<usize>,
+}
+
+/// Finds the badge of each group of `group_size` consecutive rucksacks, consuming them as
+/// they come. A group fails on its last line when its rucksacks do not share exactly one
+/// item, or when the input ends before it is complete.
+pub fn find_badges(
+    rucksacks: impl IntoIterator<Item = Rucksack>,
+    group_size: usize,
+) -> impl Iterator<Item = Result<Badge>> {
+    assert!(group_size > 0, "groups cannot be empty");
+    let mut rucksacks = rucksacks.into_iter();
+    std::iter::from_fn(move || {
+        let group = rucksacks.by_ref().take(group_size).collect_vec();
+        let last = group.last()?;
+        let lines = group.iter().map(|r| r.line).collect_vec();
+        let error = |message: String| {
+            Err(ParseError::new(Day03::DAY, last.line, &last.text, message).into())
+        };
+        let describe = lines.iter().join(", ");
+        if group.len() < group_size {
+            return Some(error(format!("incomplete group of lines {}", describe)));
+        }
+        let common = group
+            .iter()
+            .fold(ItemSet::ALL, |common, rucksack| common & rucksack.items());
+        Some(match common.single() {
+            Some(item) => Ok(Badge { item, lines }),
+            None if common.is_empty() => error(format!("lines {} share no item", describe)),
+            None => error(format!(
+                "lines {} share several items: {}",
+                describe, common
+            )),
+        })
+    })
+}
+
+const GROUP_SIZE: usize = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+    type Output1 = u32;
+    type Output2 = Attempt<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        rucksacks(input).collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .iter()
+            .flat_map(|r| (r.left & r.right).items())
+            .map(get_item_priority)
+            .sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Attempt<u32> {
+        find_badges(rucksacks.iter().cloned(), GROUP_SIZE)
+            .map(|badge| badge.map(|b| get_item_priority(b.item)))
+            .sum::<Result<u32>>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        error::Error,
+        solution::{get_example, get_input},
+    };
+
+    #[test]
+    fn test_item_priority() {
+        assert_eq!(get_item_priority('a'), 1);
+        assert_eq!(get_item_priority('z'), 26);
+        assert_eq!(get_item_priority('A'), 27);
+        assert_eq!(get_item_priority('Z'), 52);
+        assert!(('a'..='z')
+            .chain('A'..='Z')
+            .all(|item| get_item(get_item_priority(item)) == item));
+    }
+
+    #[test]
+    fn test_item_set() {
+        let left: ItemSet = "vJrwpWtwJgWr".parse().unwrap();
+        let right: ItemSet = "hcsFMMfFFhFp".parse().unwrap();
+        assert_eq!((left & right).single(), Some('p'));
+        assert_eq!((left | right).len(), 14);
+        assert!(left.contains('J') && !left.contains('j') && !left.contains('1'));
+        assert_eq!("bZa".parse::<ItemSet>().unwrap().to_string(), "abZ");
+        assert!("ab1".parse::<ItemSet>().is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day03::parse(&get_input(Day03::DAY)).unwrap();
+        assert_eq!(Day03::part1(&input), 7674);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day03::parse(&get_input(Day03::DAY)).unwrap();
+        assert_eq!(Day03::part2(&input), Attempt::Solved(2805));
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day03::parse(&get_example(Day03::DAY)).unwrap();
+        assert_eq!(Day03::part1(&input), 157);
+        assert_eq!(Day03::part2(&input), Attempt::Solved(70));
+    }
+
+    #[test]
+    fn test_badges() {
+        let example = get_example(Day03::DAY);
+        let sacks = rucksacks(&example).map(|r| r.unwrap());
+        let badges = find_badges(sacks, 3)
+            .map(|b| b.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            badges,
+            [
+                Badge {
+                    item: 'r',
+                    lines: vec![1, 2, 3]
+                },
+                Badge {
+                    item: 'Z',
+                    lines: vec![4, 5, 6]
+                },
+            ]
+        );
+        // Larger teams share fewer items
+        let message = |result: Option<Result<Badge>>| match result {
+            Some(Err(Error::Parse(error))) => (error.line, error.message),
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+        let sacks = rucksacks(&example).map(|r| r.unwrap());
+        let mut badges = find_badges(sacks, 4);
+        assert_eq!(
+            message(badges.next()),
+            (4, "lines 1, 2, 3, 4 share no item".to_string())
+        );
+        assert_eq!(
+            message(badges.next()),
+            (6, "incomplete group of lines 5, 6".to_string())
+        );
+        let sacks = rucksacks(&example).map(|r| r.unwrap());
+        let (line, single) = message(find_badges(sacks.take(1), 1).next());
+        assert_eq!(line, 1);
+        assert!(single.starts_with("lines 1 share several items"));
+    }
+
+    #[test]
+    fn test_invalid_groups() {
+        let example = get_example(Day03::DAY);
+        let incomplete = Day03::parse(&example.lines().take(4).join("\n")).unwrap();
+        // Only the badges need complete groups
+        assert_eq!(Day03::part1(&incomplete), 16 + 38 + 42 + 22);
+        assert_eq!(
+            Day03::part2(&incomplete),
+            Attempt::Unsolvable(
+                "day03, line 4: incomplete group of lines 4: \"wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\""
+                    .to_string()
+            )
+        );
+        let shared = Day03::parse("ab\nab\nab").unwrap();
+        assert!(matches!(Day03::part2(&shared), Attempt::Unsolvable(_)));
+    }
+}
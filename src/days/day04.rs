@@ -0,0 +1,95 @@
+use crate::{
+    error::{parse_lines, LineResult},
+    interval::{Interval, IntervalTree},
+    solution::{Result, Solution},
+    trace,
+};
+
+/// The sections of an assignment such as `2-4`.
+fn parse_assignment(s: &str) -> LineResult<Interval> {
+    let (start, end) = s.split_once('-').ok_or("missing '-' in assignment")?;
+    let section = |s: &str| -> LineResult<i64> { Ok(i64::try_from(s.parse::<u64>()?)?) };
+    Ok(Interval::new(section(start)?, section(end)?).ok_or("assignment ends before it starts")?)
+}
+
+/// The assignment overlapping the most others, among those of every pair, with their number.
+pub fn most_overlapped(pairs: &[(Interval, Interval)]) -> Option<(Interval, usize)> {
+    let assignments = pairs.iter().flat_map(|&(first, second)| [first, second]);
+    let tree = IntervalTree::new(assignments.clone().map(|a| (a, ())));
+    assignments
+        // The assignment overlaps itself
+        .map(|a| (a, tree.query(a).len() - 1))
+        .max_by_key(|&(a, count)| (count, std::cmp::Reverse(a)))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<(Interval, Interval)>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(Self::DAY, input, |line| {
+            let (first, second) = line
+                .split_once(',')
+                .ok_or("missing ',' between assignments")?;
+            Ok((parse_assignment(first)?, parse_assignment(second)?))
+        })
+    }
+
+    fn part1(pairs: &Self::Input) -> u64 {
+        pairs
+            .iter()
+            .filter(|(first, second)| first.covers(second) || second.covers(first))
+            .count() as u64
+    }
+
+    fn part2(pairs: &Self::Input) -> u64 {
+        if trace::enabled(trace::Level::Summary) {
+            if let Some((assignment, count)) = most_overlapped(pairs) {
+                trace!(
+                    Summary,
+                    "Sections {} overlap {} other assignments",
+                    assignment,
+                    count
+                );
+            }
+        }
+        pairs
+            .iter()
+            .filter(|(first, second)| first.overlaps(second))
+            .count() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day04::parse(&get_example(Day04::DAY)).unwrap();
+        assert_eq!(Day04::part1(&input), 2);
+        assert_eq!(Day04::part2(&input), 4);
+    }
+
+    #[test]
+    fn test_assignments() {
+        assert_eq!(
+            parse_assignment("2-4").unwrap(),
+            Interval::new(2, 4).unwrap()
+        );
+        assert!(parse_assignment("4-2").is_err() && parse_assignment("2").is_err());
+        assert!(parse_assignment("1-9223372036854775808").is_err());
+        let input = Day04::parse(&get_example(Day04::DAY)).unwrap();
+        // 2-8 meets every other assignment
+        assert_eq!(
+            most_overlapped(&input),
+            Some((Interval::new(2, 8).unwrap(), 11))
+        );
+    }
+}
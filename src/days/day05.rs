@@ -0,0 +1,369 @@
+use std::{fmt, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::{
+    error::{LineError, LineResult, ParseError},
+    solution::{Result, Solution},
+    trace,
+};
+
+/// The crates of a stack, from the bottom up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrateStack {
+    data: Vec<char>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveInstruction {
+    source: usize,
+    destination: usize,
+    amount: usize,
+}
+
+impl fmt::Display for MoveInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount,
+            self.source + 1,
+            self.destination + 1
+        )
+    }
+}
+
+impl FromStr for MoveInstruction {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let words = s.split(' ').collect_vec();
+        let ["move", amount, "from", source, "to", destination] = words[..] else {
+            return Err("expected \"move <amount> from <stack> to <stack>\"".into());
+        };
+        let stack_index = |word: &str| -> LineResult<usize> {
+            word.parse::<usize>()?
+                .checked_sub(1)
+                .ok_or_else(|| "stacks are numbered from 1".into())
+        };
+        Ok(Self {
+            amount: amount.parse()?,
+            source: stack_index(source)?,
+            destination: stack_index(destination)?,
+        })
+    }
+}
+
+fn get_stacks_and_instructions(input: &str) -> Result<(Vec<CrateStack>, Vec<MoveInstruction>)> {
+    let lines = input.lines().collect_vec();
+    let error = |index: usize, message: &str| {
+        ParseError::new(
+            Day05::DAY,
+            index + 1,
+            lines.get(index).unwrap_or(&""),
+            message,
+        )
+    };
+    let separator = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .ok_or_else(|| error(lines.len(), "missing blank line after the stack drawing"))?;
+    let stack_lines = &lines[..separator];
+    let labels = stack_lines
+        .last()
+        .ok_or_else(|| error(separator, "missing stack drawing"))?;
+    let stack_count = labels.split_whitespace().count();
+    let mut stacks = Vec::new();
+    for _ in 0..stack_count {
+        stacks.push(CrateStack { data: Vec::new() });
+    }
+    for stack_data_line in stack_lines
+        .iter()
+        .rev()
+        .skip(1)
+        .map(|s| s.chars().collect_vec())
+    {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match stack_data_line.get(1 + i * 4) {
+                None | Some(' ') => (),
+                Some(&c) => stack.data.push(c),
+            }
+        }
+    }
+    let mut instructions = vec![];
+    for (index, line) in lines.iter().enumerate().skip(separator + 1) {
+        if line.is_empty() {
+            break;
+        }
+        let instruction: MoveInstruction = line
+            .parse()
+            .map_err(|e: LineError| error(index, &e.to_string()))?;
+        if instruction.source.max(instruction.destination) >= stack_count {
+            return Err(error(index, &format!("there are only {} stacks", stack_count)).into());
+        }
+        instructions.push((index, instruction));
+    }
+    // Every instruction must find enough crates, whichever the crane
+    let mut crane = Crane::new(CraneModel::CrateMover9000, &stacks, vec![]);
+    for (index, instruction) in &instructions {
+        crane.instructions.push(instruction.clone());
+        crane.step().map_err(|e| error(*index, &e.to_string()))?;
+    }
+    Ok((stacks, crane.instructions))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CraneModel {
+    /// Moves the crates one at a time, reversing their order
+    CrateMover9000,
+    /// Moves the crates all at once, keeping their order
+    CrateMover9001,
+}
+
+/// An instruction that takes more crates than its stack holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    /// The index of the instruction
+    pub step: usize,
+    pub instruction: MoveInstruction,
+    /// The crates in the stack
+    pub available: usize,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "instruction {} ({}) takes {} crates from stack {}, which holds {}",
+            self.step + 1,
+            self.instruction,
+            self.instruction.amount,
+            self.instruction.source + 1,
+            self.available
+        )
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+/// A move done by the crane, with the crates it moved so that it can be undone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Operation {
+    source: usize,
+    destination: usize,
+    /// The crates, from the bottom up as they were on the source stack
+    crates: Vec<char>,
+}
+
+/// Follows the instructions one step at a time, forwards or backwards.
+#[derive(Debug, Clone)]
+pub struct Crane {
+    model: CraneModel,
+    stacks: Vec<CrateStack>,
+    instructions: Vec<MoveInstruction>,
+    /// The operations done so far, one per instruction followed
+    history: Vec<Operation>,
+}
+
+impl Crane {
+    pub fn new(
+        model: CraneModel,
+        stacks: &[CrateStack],
+        instructions: Vec<MoveInstruction>,
+    ) -> Self {
+        Crane {
+            model,
+            stacks: stacks.to_vec(),
+            instructions,
+            history: vec![],
+        }
+    }
+
+    /// Number of instructions followed.
+    pub fn position(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.position() == self.instructions.len()
+    }
+
+    /// Follows the next instruction, returning `false` when they are all followed. An
+    /// instruction taking more crates than its stack holds leaves the stacks as they are.
+    pub fn step(&mut self) -> std::result::Result<bool, MoveError> {
+        let step = self.position();
+        let Some(instruction) = self.instructions.get(step) else {
+            return Ok(false);
+        };
+        let source = &mut self.stacks[instruction.source].data;
+        let Some(start) = source.len().checked_sub(instruction.amount) else {
+            return Err(MoveError {
+                step,
+                instruction: instruction.clone(),
+                available: source.len(),
+            });
+        };
+        let crates = source.split_off(start);
+        let destination = &mut self.stacks[instruction.destination].data;
+        match self.model {
+            CraneModel::CrateMover9000 => destination.extend(crates.iter().rev()),
+            CraneModel::CrateMover9001 => destination.extend(&crates),
+        }
+        self.history.push(Operation {
+            source: instruction.source,
+            destination: instruction.destination,
+            crates,
+        });
+        Ok(true)
+    }
+
+    /// Undoes the last instruction followed, returning `false` at the start.
+    pub fn back(&mut self) -> bool {
+        let Some(operation) = self.history.pop() else {
+            return false;
+        };
+        let destination = &mut self.stacks[operation.destination].data;
+        destination.truncate(destination.len() - operation.crates.len());
+        self.stacks[operation.source].data.extend(operation.crates);
+        true
+    }
+
+    /// Steps forwards or backwards until `position` instructions are followed, or as far as
+    /// the instructions go.
+    pub fn seek(&mut self, position: usize) -> std::result::Result<(), MoveError> {
+        while self.position() > position {
+            self.back();
+        }
+        while self.position() < position && self.step()? {}
+        Ok(())
+    }
+
+    /// Follows the remaining instructions.
+    pub fn run(&mut self) -> std::result::Result<(), MoveError> {
+        self.seek(self.instructions.len())
+    }
+
+    /// The crate on top of each stack.
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.data.last())
+            .collect()
+    }
+
+    /// The stacks drawn like in the input, labels included.
+    pub fn render(&self) -> String {
+        let height = self.stacks.iter().map(|s| s.data.len()).max().unwrap_or(0);
+        let mut lines = (0..height)
+            .rev()
+            .map(|level| {
+                self.stacks
+                    .iter()
+                    .map(|stack| match stack.data.get(level) {
+                        Some(c) => format!("[{}]", c),
+                        None => "   ".to_string(),
+                    })
+                    .join(" ")
+            })
+            .collect_vec();
+        lines.push(
+            (1..=self.stacks.len())
+                .map(|label| format!("{:^3}", label))
+                .join(" "),
+        );
+        lines.join("\n")
+    }
+}
+
+/// The crates on top once `model` followed the instructions, tracing the stacks after each.
+fn top_crates(
+    model: CraneModel,
+    (stacks, instructions): &(Vec<CrateStack>, Vec<MoveInstruction>),
+) -> String {
+    let mut crane = Crane::new(model, stacks, instructions.clone());
+    trace!(Steps, "Start:\n{}", crane.render());
+    while crane.step().expect("the instructions were checked") {
+        let instruction = &crane.instructions[crane.position() - 1];
+        trace!(Steps, "After {}:\n{}", instruction, crane.render());
+    }
+    crane.tops()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Vec<CrateStack>, Vec<MoveInstruction>);
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_stacks_and_instructions(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        top_crates(CraneModel::CrateMover9000, input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        top_crates(CraneModel::CrateMover9001, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{error::Error, solution::get_example};
+
+    #[test]
+    fn test_invalid_instruction() {
+        let Err(Error::Parse(error)) = Day05::parse("[A] [B]\n 1   2 \n\nmove 1 from 1 to 3\n")
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.line, 4);
+        assert_eq!(error.text, "move 1 from 1 to 3");
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day05::parse(&get_example(Day05::DAY)).unwrap();
+        assert_eq!(Day05::part1(&input), "CMZ");
+        assert_eq!(Day05::part2(&input), "MCD");
+    }
+
+    #[test]
+    fn test_too_many_crates() {
+        let Err(Error::Parse(error)) =
+            Day05::parse("[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 3 from 2 to 1\n")
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.line, 5);
+        assert_eq!(
+            error.message,
+            "instruction 2 (move 3 from 2 to 1) takes 3 crates from stack 2, which holds 2"
+        );
+    }
+
+    #[test]
+    fn test_crane() {
+        let example = get_example(Day05::DAY);
+        let (stacks, instructions) = Day05::parse(&example).unwrap();
+        let mut crane = Crane::new(CraneModel::CrateMover9001, &stacks, instructions);
+        let drawing = example.split("\n\n").next().unwrap();
+        assert_eq!(crane.render(), drawing);
+        crane.seek(2).unwrap();
+        assert_eq!(
+            crane.render(),
+            "        [D]\n        [N]\n    [C] [Z]\n    [M] [P]\n 1   2   3 "
+        );
+        crane.run().unwrap();
+        assert!(crane.is_done() && !crane.step().unwrap());
+        assert_eq!(crane.tops(), "MCD");
+        // Undoing every step brings back the drawing
+        while crane.back() {}
+        assert_eq!((crane.position(), crane.render()), (0, drawing.to_string()));
+    }
+}
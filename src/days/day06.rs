@@ -0,0 +1,60 @@
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    solution::{Result, Solution},
+};
+
+fn detect_pattern(pattern_length: usize, payload: &str) -> u32 {
+    let mut buffer = vec![0; pattern_length];
+
+    let mut buffer_i = 0;
+    for (index, c) in payload.chars().enumerate() {
+        buffer[buffer_i] = c as u8;
+        buffer_i = (buffer_i + 1) % pattern_length;
+        if index >= pattern_length && buffer.iter().unique().count() == pattern_length {
+            return (index + 1) as u32;
+        }
+    }
+    0
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        match input.lines().next() {
+            Some(line) if !line.is_empty() => Ok(line.to_string()),
+            _ => Err(ParseError::new(Self::DAY, 1, "", "empty datastream").into()),
+        }
+    }
+
+    fn part1(line: &Self::Input) -> u32 {
+        const PATTERN_LENGTH: usize = 4;
+        detect_pattern(PATTERN_LENGTH, line)
+    }
+
+    fn part2(line: &Self::Input) -> u32 {
+        const PATTERN_LENGTH: usize = 14;
+        detect_pattern(PATTERN_LENGTH, line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day06::parse(&get_example(Day06::DAY)).unwrap();
+        assert_eq!(Day06::part1(&input), 7);
+        assert_eq!(Day06::part2(&input), 19);
+    }
+}
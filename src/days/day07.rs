@@ -0,0 +1,189 @@
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    solution::{Result, Solution},
+};
+
+#[derive(Debug)]
+pub enum Node {
+    File(String, usize),
+    Directory(String),
+}
+
+#[derive(Debug)]
+pub struct Tree {
+    node: Node,
+    children: Vec<Rc<RefCell<Tree>>>,
+}
+
+impl Tree {
+    pub fn new(node: Node) -> Self {
+        Self {
+            node,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn insert_child(&mut self, child: Node) -> Rc<RefCell<Tree>> {
+        let tree = Self::new(child);
+        let r = Rc::new(RefCell::new(tree));
+        self.children.push(Rc::clone(&r));
+        Rc::clone(&r)
+    }
+
+    pub fn find_child(&self, name: &str) -> Option<Rc<RefCell<Tree>>> {
+        let r = self.children.iter().find(|tree| {
+            let (Node::File(iname, _) | Node::Directory(iname)) = &tree.borrow().node;
+            name == iname.as_str()
+        });
+        r.map(Rc::clone)
+    }
+}
+
+fn get_tree(input: &str) -> Result<Rc<RefCell<Tree>>> {
+    let lines = &mut input.lines().enumerate().peekable();
+    let root = Rc::new(RefCell::new(Tree::new(Node::Directory("/".to_string()))));
+    let mut dir_history = Vec::new();
+    let mut current_tree = Rc::clone(&root);
+    while let Some((index, line)) = lines.next() {
+        let error = |message: &str| ParseError::new(Day07::DAY, index + 1, line, message);
+        let cmd = line
+            .strip_prefix("$ ")
+            .ok_or_else(|| error("expected a command"))?;
+        match cmd.split(' ').collect_vec()[..] {
+            ["cd", dir] => {
+                current_tree = match dir {
+                    ".." => dir_history
+                        .pop()
+                        .ok_or_else(|| error("no parent directory"))?,
+                    "/" => {
+                        dir_history.clear();
+                        Rc::clone(&root)
+                    }
+                    s => {
+                        dir_history.push(Rc::clone(&current_tree));
+                        let mut current = current_tree.deref().borrow_mut();
+                        match current.find_child(s) {
+                            None => current.insert_child(Node::Directory(s.to_string())),
+                            Some(node) => node,
+                        }
+                    }
+                };
+            }
+            ["ls"] => {
+                while let Some((index, item)) = lines.next_if(|(_, l)| !l.starts_with('$')) {
+                    let error =
+                        |message: &str| ParseError::new(Day07::DAY, index + 1, item, message);
+                    let (size, name) = item
+                        .split_once(' ')
+                        .ok_or_else(|| error("expected \"dir <name>\" or \"<size> <name>\""))?;
+                    if size != "dir" {
+                        let size: usize = size.parse().map_err(|_| error("invalid file size"))?;
+                        let name = name.to_string();
+                        if current_tree.borrow().find_child(&name).is_none() {
+                            current_tree
+                                .deref()
+                                .borrow_mut()
+                                .insert_child(Node::File(name, size));
+                        }
+                    }
+                }
+            }
+            _ => return Err(error("unknown command").into()),
+        }
+    }
+    Ok(root)
+}
+
+const DISK_SIZE: usize = 70_000_000;
+const REQUIRED_SIZE: usize = 30_000_000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Rc<RefCell<Tree>>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_tree(input)
+    }
+
+    fn part1(root: &Self::Input) -> u32 {
+        let mut small_dirs = Vec::new();
+        fn compute(small_dirs: &mut Vec<u32>, tree: Rc<RefCell<Tree>>) -> usize {
+            let tree = tree.borrow();
+            match tree.node {
+                Node::Directory(_) => {
+                    let s = tree
+                        .children
+                        .iter()
+                        .map(|t| compute(small_dirs, Rc::clone(t)))
+                        .sum();
+                    if s < 100_000 {
+                        small_dirs.push(s as u32);
+                    }
+                    s
+                }
+                Node::File(_, size) => size,
+            }
+        }
+        compute(&mut small_dirs, Rc::clone(root));
+        small_dirs.iter().sum()
+    }
+
+    fn part2(root: &Self::Input) -> u32 {
+        fn get_size(tree: Rc<RefCell<Tree>>) -> usize {
+            let tree = tree.borrow();
+            match tree.node {
+                Node::Directory(_) => tree.children.iter().map(|t| get_size(Rc::clone(t))).sum(),
+                Node::File(_, size) => size,
+            }
+        }
+        let used_size = get_size(Rc::clone(root));
+        let free_space = DISK_SIZE - used_size;
+        let size_required = REQUIRED_SIZE - free_space;
+        let mut candidates: Vec<u32> = Vec::new();
+        fn compute(
+            candidates: &mut Vec<u32>,
+            size_required: usize,
+            tree: Rc<RefCell<Tree>>,
+        ) -> usize {
+            let tree = tree.borrow();
+            match tree.node {
+                Node::Directory(_) => {
+                    let s = tree
+                        .children
+                        .iter()
+                        .map(|t| compute(candidates, size_required, Rc::clone(t)))
+                        .sum();
+                    if s >= size_required {
+                        candidates.push(s as u32);
+                    }
+                    s
+                }
+                Node::File(_, size) => size,
+            }
+        }
+        compute(&mut candidates, size_required, Rc::clone(root));
+        *candidates.iter().min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day07::parse(&get_example(Day07::DAY)).unwrap();
+        assert_eq!(Day07::part1(&input), 95437);
+        assert_eq!(Day07::part2(&input), 24933642);
+    }
+}
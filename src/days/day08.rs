@@ -0,0 +1,76 @@
+use crate::{
+    grid::{Coord, Grid, ORTHOGONAL},
+    solution::{Result, Solution},
+};
+
+fn get_grid(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(Day08::DAY, input, |c| {
+        c.to_digit(10)
+            .map(|v| v as u8)
+            .ok_or_else(|| format!("invalid height {:?}", c).into())
+    })
+}
+
+/// Whether the tree at `coord` can be seen from outside the grid in at least one direction.
+fn is_visible(grid: &Grid<u8>, coord: Coord) -> bool {
+    let height = grid[coord];
+    ORTHOGONAL
+        .into_iter()
+        .any(|delta| grid.ray(coord, delta).all(|c| grid[c] < height))
+}
+
+/// Product of the viewing distances of the tree at `coord` in each direction.
+fn get_scenic_score(grid: &Grid<u8>, coord: Coord) -> u32 {
+    let height = grid[coord];
+    ORTHOGONAL
+        .into_iter()
+        .map(|delta| {
+            let mut distance = 0;
+            for c in grid.ray(coord, delta) {
+                distance += 1;
+                if grid[c] >= height {
+                    break;
+                }
+            }
+            distance
+        })
+        .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Grid<u8>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> u32 {
+        grid.coords().filter(|&c| is_visible(grid, c)).count() as u32
+    }
+
+    fn part2(grid: &Self::Input) -> u32 {
+        grid.coords()
+            .map(|c| get_scenic_score(grid, c))
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day08::parse(&get_example(Day08::DAY)).unwrap();
+        assert_eq!(Day08::part1(&input), 21);
+        assert_eq!(Day08::part2(&input), 8);
+    }
+}
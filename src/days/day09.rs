@@ -0,0 +1,89 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::{
+    error::parse_lines,
+    geometry::{Direction4, Point2},
+    solution::{Result, Solution},
+};
+
+fn parse_moves(input: &str) -> Result<Vec<Direction4>> {
+    let lines = parse_lines(Day09::DAY, input, |l| {
+        let (direction, n) = l.split_once(' ').ok_or("missing move length")?;
+        let m = match direction {
+            "U" => Direction4::Up,
+            "L" => Direction4::Left,
+            "R" => Direction4::Right,
+            "D" => Direction4::Down,
+            _ => return Err(format!("unknown move {:?}", direction).into()),
+        };
+        Ok(vec![m; n.parse()?])
+    })?;
+    Ok(lines.into_iter().flatten().collect_vec())
+}
+
+/// Where a knot moves to follow `head`: it stays put while they touch, diagonals included,
+/// and otherwise takes one step towards it.
+fn follow(head: Point2, knot: Point2) -> Point2 {
+    if head.chebyshev(knot) <= 1 {
+        knot
+    } else {
+        knot + (head - knot).signum()
+    }
+}
+
+const KNOTS: usize = 10;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Direction4>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Self::Input) -> u32 {
+        let mut tail_visited = HashSet::new();
+        let mut head_pos = Point2::ORIGIN;
+        let mut tail_pos = Point2::ORIGIN;
+        tail_visited.insert(tail_pos);
+        for &m in moves {
+            head_pos += m;
+            tail_pos = follow(head_pos, tail_pos);
+            tail_visited.insert(tail_pos);
+        }
+        tail_visited.len() as u32
+    }
+
+    fn part2(moves: &Self::Input) -> u32 {
+        let mut tail_visited = HashSet::new();
+        let mut knots = [Point2::ORIGIN; KNOTS];
+        tail_visited.insert(knots[KNOTS - 1]);
+        for &m in moves {
+            knots[0] += m;
+            for i in 1..KNOTS {
+                knots[i] = follow(knots[i - 1], knots[i]);
+            }
+            tail_visited.insert(knots[KNOTS - 1]);
+        }
+        tail_visited.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day09::parse(&get_example(Day09::DAY)).unwrap();
+        assert_eq!(Day09::part1(&input), 13);
+        assert_eq!(Day09::part2(&input), 1);
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+
+use crate::{
+    error::parse_lines,
+    solution::{Result, Solution},
+    trace,
+};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+fn get_instructions(input: &str) -> Result<Vec<Instruction>> {
+    parse_lines(Day10::DAY, input, |s| match s.split_once(' ') {
+        None if s == "noop" => Ok(Instruction::Noop),
+        Some(("addx", v)) => Ok(Instruction::AddX(v.parse()?)),
+        _ => Err("unknown instruction".into()),
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> i32 {
+        let mut execution_buffer = VecDeque::new();
+        let mut rx: i32 = 1;
+        let mut signal_sum: i32 = 0;
+        let mut pc = 0;
+        let mut cycle = 1;
+
+        while pc < instructions.len() || !execution_buffer.is_empty() {
+            if let Some(instr) = execution_buffer.pop_back() {
+                trace!(Steps, "Cycle {} -> instr={:?} rx={}", cycle, instr, rx);
+
+                if let Some(Instruction::AddX(dx)) = instr {
+                    rx += dx;
+                }
+                cycle += 1;
+                if (20..=220).contains(&cycle) && (cycle - 20) % 40 == 0 {
+                    let signal = cycle * rx;
+                    signal_sum += signal;
+                }
+            } else {
+                match instructions[pc] {
+                    Instruction::Noop => execution_buffer.push_front(None),
+                    Instruction::AddX(dx) => {
+                        execution_buffer.push_front(None);
+                        execution_buffer.push_front(Some(Instruction::AddX(dx)));
+                    }
+                }
+                pc += 1;
+            }
+        }
+        signal_sum
+    }
+
+    fn part2(instructions: &Self::Input) -> String {
+        let mut execution_buffer = VecDeque::new();
+        let mut rx: i32 = 1;
+        let mut pc = 0;
+        let mut cycle = 1;
+        let mut crt = [[false; 40]; 6];
+        while pc < instructions.len() || !execution_buffer.is_empty() {
+            if let Some(instr) = execution_buffer.pop_back() {
+                trace!(Steps, "Cycle {} -> instr={:?} rx={}", cycle, instr, rx);
+                let (crt_i, crt_j) = ((cycle - 1) / 40, (cycle - 1) % 40);
+                if (crt_j as i32).abs_diff(rx) <= 1 {
+                    crt[crt_i][crt_j] = true;
+                }
+                if let Instruction::AddX(dx) = instr {
+                    rx += dx;
+                }
+                cycle += 1;
+            } else {
+                match instructions[pc] {
+                    Instruction::Noop => execution_buffer.push_front(Instruction::Noop),
+                    Instruction::AddX(dx) => {
+                        execution_buffer.push_front(Instruction::Noop);
+                        execution_buffer.push_front(Instruction::AddX(dx));
+                    }
+                }
+                pc += 1;
+            }
+        }
+        crt.iter()
+            .map(|row| row.map(|b| if b { "#" } else { "." }).join(""))
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day10::parse(&get_example(Day10::DAY)).unwrap();
+        assert_eq!(Day10::part1(&input), 13140);
+        assert_eq!(
+            Day10::part2(&input),
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]
+            .join("\n")
+        );
+    }
+}
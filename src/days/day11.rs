@@ -0,0 +1,200 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    solution::{Result, Solution},
+};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Square,
+    Multiply(u64),
+    Add(u64),
+}
+
+#[derive(Debug, Clone)]
+enum Test {
+    DivisibleBy(u64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+    recipient_if_true: usize,
+    recipient_if_false: usize,
+}
+
+fn apply_operation(operation: &Operation, item: u64) -> u64 {
+    use Operation::*;
+    match operation {
+        Multiply(x) => item * x,
+        Add(x) => item + x,
+        Square => item.pow(2),
+    }
+}
+
+fn get_test_outcome(test: &Test, item: u64) -> bool {
+    match test {
+        Test::DivisibleBy(x) => item.is_multiple_of(*x),
+    }
+}
+
+fn get_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let mut out = vec![];
+
+    let lines = input.lines().collect_vec();
+    for start in (0..lines.len()).step_by(7) {
+        let error = |offset: usize, message: &dyn Display| {
+            let index = start + offset;
+            let line = lines.get(index).unwrap_or(&"");
+            ParseError::new(Day11::DAY, index + 1, line, message)
+        };
+        let field = |offset: usize, prefix: &str| {
+            lines
+                .get(start + offset)
+                .and_then(|l| l.trim().strip_prefix(prefix))
+                .ok_or_else(|| error(offset, &format!("expected {:?}", prefix)))
+        };
+        field(0, "Monkey ")?;
+        let items = field(1, "Starting items: ")?
+            .split(", ")
+            .map(|s| s.parse::<u64>())
+            .collect::<std::result::Result<VecDeque<_>, _>>()
+            .map_err(|e| error(1, &e))?;
+        let operation = match field(2, "Operation: new = old ")?.split_once(' ') {
+            Some(("*", "old")) => Operation::Square,
+            Some(("*", val)) => Operation::Multiply(val.parse().map_err(|e| error(2, &e))?),
+            Some(("+", val)) => Operation::Add(val.parse().map_err(|e| error(2, &e))?),
+            _ => return Err(error(2, &"unknown operation").into()),
+        };
+        let test = Test::DivisibleBy(
+            field(3, "Test: divisible by ")?
+                .parse()
+                .map_err(|e| error(3, &e))?,
+        );
+        let recipient_if_true = field(4, "If true: throw to monkey ")?
+            .parse()
+            .map_err(|e| error(4, &e))?;
+        let recipient_if_false = field(5, "If false: throw to monkey ")?
+            .parse()
+            .map_err(|e| error(5, &e))?;
+
+        out.push(Monkey {
+            items,
+            operation,
+            test,
+            recipient_if_true,
+            recipient_if_false,
+        })
+    }
+    for (i, monkey) in out.iter().enumerate() {
+        for (offset, recipient) in [
+            (4, monkey.recipient_if_true),
+            (5, monkey.recipient_if_false),
+        ] {
+            if recipient >= out.len() {
+                let index = i * 7 + offset;
+                let message = format!("there is no monkey {}", recipient);
+                return Err(ParseError::new(Day11::DAY, index + 1, lines[index], message).into());
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_monkeys(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> u64 {
+        let mut monkeys = monkeys.clone();
+        let mut inspection_count = vec![0u64; monkeys.len()];
+        const ROUNDS: u64 = 20;
+        let monkey_count = monkeys.len();
+        for _ in 0..ROUNDS {
+            for i in 0..monkey_count {
+                while !monkeys[i].items.is_empty() {
+                    let item = monkeys[i].items.pop_front().unwrap();
+                    inspection_count[i] += 1;
+                    let worry_level = apply_operation(&monkeys[i].operation, item) / 3;
+                    let recipient = if get_test_outcome(&monkeys[i].test, worry_level) {
+                        monkeys[i].recipient_if_true
+                    } else {
+                        monkeys[i].recipient_if_false
+                    };
+                    monkeys[recipient].items.push_back(worry_level);
+                }
+            }
+        }
+        inspection_count.sort();
+        let (first, second) = inspection_count
+            .iter()
+            .rev()
+            .take(2)
+            .collect_tuple()
+            .unwrap();
+        first * second
+    }
+
+    fn part2(monkeys: &Self::Input) -> u64 {
+        let mut monkeys = monkeys.clone();
+        let mut inspection_count = vec![0u64; monkeys.len()];
+        let lcm: u64 = monkeys
+            .iter()
+            .map(|m| match m.test {
+                Test::DivisibleBy(x) => x,
+            })
+            .product();
+        const ROUNDS: u64 = 10000;
+        let monkey_count = monkeys.len();
+        for _ in 0..ROUNDS {
+            for i in 0..monkey_count {
+                while !monkeys[i].items.is_empty() {
+                    let item = monkeys[i].items.pop_front().unwrap();
+                    inspection_count[i] += 1;
+                    let worry_level = apply_operation(&monkeys[i].operation, item);
+                    let recipient = if get_test_outcome(&monkeys[i].test, worry_level) {
+                        monkeys[i].recipient_if_true
+                    } else {
+                        monkeys[i].recipient_if_false
+                    };
+                    monkeys[recipient].items.push_back(worry_level % lcm);
+                }
+            }
+        }
+        inspection_count.sort();
+        let (first, second) = inspection_count
+            .iter()
+            .rev()
+            .take(2)
+            .collect_tuple()
+            .unwrap();
+        first * second
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day11::parse(&get_example(Day11::DAY)).unwrap();
+        assert_eq!(Day11::part1(&input), 10605);
+        assert_eq!(Day11::part2(&input), 2713310158);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    error::ParseError,
+    grid::{Coord, Grid},
+    search::{bfs, dijkstra},
+    solution::{Result, Solution},
+};
+
+fn get_current_height(c: char) -> i32 {
+    match c {
+        'S' => get_current_height('a'),
+        'E' => get_current_height('z'),
+        c if c.is_ascii_lowercase() => c as i32 - 'a' as i32,
+        _ => panic!("Unknown height character"),
+    }
+}
+
+/// The cells reachable in one step from `coord`: at most one unit higher.
+fn climbable<'a>(heights: &'a Grid<i32>, coord: &Coord) -> impl Iterator<Item = Coord> + 'a {
+    let (coord, height) = (*coord, heights[*coord]);
+    heights
+        .neighbours4(coord)
+        .filter(move |&n| heights[n] - height <= 1)
+}
+
+fn find(grid: &Grid<char>, marker: char) -> Coord {
+    grid.position(|&c| c == marker)
+        .unwrap_or_else(|| panic!("{:?} not found", marker))
+}
+
+/// The starting cells of part 2: every cell at the lowest elevation.
+fn lowest_cells(grid: &Grid<char>) -> impl Iterator<Item = Coord> + '_ {
+    grid.iter()
+        .filter(|(_, &c)| c == 'a' || c == 'S')
+        .map(|(coord, _)| coord)
+}
+
+fn shortest_bfs(grid: &Grid<char>, starts: impl IntoIterator<Item = Coord>) -> usize {
+    let heights = grid.map(|&c| get_current_height(c));
+    let end = find(grid, 'E');
+    bfs(starts, |c| climbable(&heights, c), |&c| c == end)
+        .goal_distance()
+        .expect("path not found")
+}
+
+fn shortest_dijkstra(grid: &Grid<char>, starts: impl IntoIterator<Item = Coord>) -> usize {
+    let heights = grid.map(|&c| get_current_height(c));
+    let end = find(grid, 'E');
+    dijkstra(
+        starts,
+        |c| climbable(&heights, c).map(|n| (n, 1)),
+        |&c| c == end,
+    )
+    .goal_distance()
+    .expect("path not found")
+}
+
+pub fn part1_dijsktra(grid: &Grid<char>) -> usize {
+    shortest_dijkstra(grid, [find(grid, 'S')])
+}
+
+pub fn part1_bfs(grid: &Grid<char>) -> usize {
+    shortest_bfs(grid, [find(grid, 'S')])
+}
+
+pub fn part2_dijsktra(grid: &Grid<char>) -> usize {
+    shortest_dijkstra(grid, lowest_cells(grid))
+}
+
+pub fn part2_bfs(grid: &Grid<char>) -> usize {
+    shortest_bfs(grid, lowest_cells(grid))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(Self::DAY, input, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            _ => Err(format!("unknown height character {:?}", c).into()),
+        })?;
+        for marker in ['S', 'E'] {
+            if grid.position(|&c| c == marker).is_none() {
+                let message = format!("missing {:?} marker", marker);
+                return Err(ParseError::new(Self::DAY, grid.height() + 1, "", message).into());
+            }
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part1_bfs(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2_bfs(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day12::parse(&get_example(Day12::DAY)).unwrap();
+        assert_eq!(part1_bfs(&input), 31);
+        assert_eq!(part1_dijsktra(&input), 31);
+        assert_eq!(part2_bfs(&input), 29);
+        assert_eq!(part2_dijsktra(&input), 29);
+    }
+}
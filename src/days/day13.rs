@@ -0,0 +1,177 @@
+use std::{cmp::Ordering, vec};
+
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::{
+    error::{parse_lines, LineResult, ParseError},
+    solution::{Result, Solution},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    List(Vec<Item>),
+    Integer(u64),
+}
+
+fn parse_item(line: &str) -> LineResult<Item> {
+    if !line.starts_with('[') {
+        Ok(Item::Integer(line.parse::<u64>()?))
+    } else {
+        let line = line
+            .strip_prefix('[')
+            .and_then(|l| l.strip_suffix(']'))
+            .ok_or("unbalanced brackets")?;
+        let mut current_list = vec![];
+        let mut buffer = String::new();
+        let mut depth = 0;
+        for c in line.chars() {
+            match c {
+                ',' if depth == 0 => {
+                    if !buffer.is_empty() {
+                        current_list.push(parse_item(&buffer)?);
+                        buffer.clear();
+                    }
+                }
+                '[' => {
+                    depth += 1;
+                    buffer.push(c);
+                }
+                ']' => {
+                    if depth == 0 {
+                        return Err("unbalanced brackets".into());
+                    }
+                    depth -= 1;
+                    buffer.push(c);
+                    if depth == 0 {
+                        current_list.push(parse_item(&buffer)?);
+                        buffer.clear();
+                    }
+                }
+                c => buffer.push(c),
+            }
+        }
+        if depth != 0 {
+            return Err("unbalanced brackets".into());
+        }
+        if !buffer.is_empty() {
+            current_list.push(parse_item(&buffer)?);
+        }
+        Ok(Item::List(current_list))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Item::*;
+        match (self, other) {
+            (Integer(a), Integer(b)) => a.cmp(b),
+            (List(a), List(b)) => {
+                for e in a.iter().zip_longest(b.iter()) {
+                    match e {
+                        EitherOrBoth::Both(a, b) => {
+                            let res = a.cmp(b);
+                            if res != Ordering::Equal {
+                                return res;
+                            }
+                        }
+                        EitherOrBoth::Right(_) => return Ordering::Less,
+                        EitherOrBoth::Left(_) => return Ordering::Greater,
+                    }
+                }
+                Ordering::Equal
+            }
+            (List(a), Integer(b)) => List(a.clone()).cmp(&List(vec![Integer(*b)])),
+            (Integer(_), List(_)) => other.cmp(self).reverse(),
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<(Item, Item)>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let packets = parse_lines(Self::DAY, input, |line| {
+            if line.is_empty() {
+                Ok(None)
+            } else {
+                parse_item(line).map(Some)
+            }
+        })?;
+        let mut pairs = vec![];
+        for (i, chunk) in packets.chunks(3).enumerate() {
+            match chunk {
+                [Some(a), Some(b)] | [Some(a), Some(b), None] => pairs.push((a.clone(), b.clone())),
+                _ => {
+                    let line = input.lines().nth(i * 3).unwrap_or_default();
+                    let message = "expected two packets followed by a blank line";
+                    return Err(ParseError::new(Self::DAY, i * 3 + 1, line, message).into());
+                }
+            }
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> u32 {
+        let mut sum = 0;
+        for (i, (a, b)) in pairs.iter().enumerate() {
+            if a < b {
+                sum += i + 1
+            }
+        }
+        sum as u32
+    }
+
+    fn part2(pairs: &Self::Input) -> u32 {
+        use Item::*;
+        let mut items = vec![];
+        for (a, b) in pairs {
+            items.push(a.clone());
+            items.push(b.clone());
+        }
+        let dividers = [
+            List(vec![List(vec![Integer(2)])]),
+            List(vec![List(vec![Integer(6)])]),
+        ];
+        items.extend(dividers.clone());
+        items.sort();
+        let divider_indexes = dividers.map(|d| items.binary_search(&d).expect("divider not found"));
+        divider_indexes.iter().map(|i| i + 1).product::<usize>() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_parse_item() {
+        use Item::*;
+        assert_eq!(
+            parse_item("[1,[2,[]]]").unwrap(),
+            List(vec![Integer(1), List(vec![Integer(2), List(vec![])])])
+        );
+        assert!(parse_item("[1,[2]").is_err());
+        assert!(parse_item("[1]]").is_err());
+        assert!(parse_item("[a]").is_err());
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day13::parse(&get_example(Day13::DAY)).unwrap();
+        assert_eq!(Day13::part1(&input), 13);
+        assert_eq!(Day13::part2(&input), 140);
+    }
+}
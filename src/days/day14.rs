@@ -0,0 +1,212 @@
+use std::iter;
+
+use itertools::{Itertools, MinMaxResult};
+
+use crate::{
+    animation::{Animated, Frame, Frames, Glyph, Palette, Rgb},
+    error::{parse_lines, LineResult, ParseError},
+    grid::{Coord, Grid},
+    solution::{Result, Solution},
+};
+
+fn get_rock_path(input: &str) -> Result<Vec<Vec<(usize, usize)>>> {
+    let paths = parse_lines(Day14::DAY, input, |line| {
+        let path = line
+            .split(" -> ")
+            .map(|s| -> LineResult<(usize, usize)> {
+                let (x, y) = s.split_once(',').ok_or("missing ',' in point")?;
+                let x: usize = x.parse()?;
+                if !(X_OFFSET..X_OFFSET + SIM_WIDTH).contains(&x) {
+                    return Err(format!("x={} is outside of the simulated area", x).into());
+                }
+                Ok((x, y.parse()?))
+            })
+            .collect::<LineResult<Vec<_>>>()?;
+        if path
+            .iter()
+            .tuple_windows()
+            .any(|(a, b)| a.0 != b.0 && a.1 != b.1)
+        {
+            return Err("rock paths must be horizontal or vertical".into());
+        }
+        Ok(path)
+    })?;
+    if paths.iter().all(|path| path.is_empty()) {
+        return Err(ParseError::new(Day14::DAY, 1, "", "no rock path").into());
+    }
+    Ok(paths)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Content {
+    Source,
+    Empty,
+    Sand,
+    Rock,
+}
+
+impl Content {
+    fn is_occupied(&self) -> bool {
+        match self {
+            Self::Empty | Self::Source => false,
+            Self::Sand | Self::Rock => true,
+        }
+    }
+}
+
+impl Glyph for Content {
+    fn glyph(&self) -> &'static str {
+        match self {
+            Content::Empty => "⬜",
+            Content::Rock => "⬛",
+            Content::Sand => "🟨",
+            Content::Source => "🟥",
+        }
+    }
+}
+
+impl Palette for Content {
+    fn colour(&self) -> Rgb {
+        match self {
+            Content::Empty => [245, 245, 240],
+            Content::Rock => [70, 70, 80],
+            Content::Sand => [235, 190, 60],
+            Content::Source => [210, 50, 40],
+        }
+    }
+}
+
+/// Where a grain of sand falling from `pos` comes to rest, `None` if it falls below
+/// `max_depth`. Sand falls straight down, else down-left, else down-right.
+fn get_next_sand_pos(grid: &Grid<Content>, max_depth: usize, pos: Coord) -> Option<Coord> {
+    if pos.row >= max_depth {
+        return None;
+    }
+    [(1, 0), (1, -1), (1, 1)]
+        .into_iter()
+        .filter_map(|delta| grid.offset(pos, delta))
+        .find(|&next| !grid[next].is_occupied())
+        .map_or(Some(pos), |next| get_next_sand_pos(grid, max_depth, next))
+}
+
+const SOURCE_X: usize = 500;
+const SIM_WIDTH: usize = 400;
+const X_OFFSET: usize = SOURCE_X - SIM_WIDTH / 2;
+const SOURCE: Coord = Coord::new(0, SOURCE_X - X_OFFSET);
+
+fn initial_fill(grid: &mut Grid<Content>, paths: &[Vec<(usize, usize)>]) {
+    grid[SOURCE] = Content::Source;
+    for path in paths {
+        for i in 1..path.len() {
+            let ((a_x, a_y), (b_x, b_y)) = (path[i - 1], path[i]);
+            if a_x == b_x {
+                let (ty, by) = if a_y < b_y { (a_y, b_y) } else { (b_y, a_y) };
+                for y in ty..=by {
+                    grid[Coord::new(y, a_x - X_OFFSET)] = Content::Rock;
+                }
+            } else {
+                let (lx, rx) = if a_x < b_x { (a_x, b_x) } else { (b_x, a_x) };
+                for x in lx..=rx {
+                    grid[Coord::new(a_y, x - X_OFFSET)] = Content::Rock;
+                }
+            }
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Vec<(usize, usize)>>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_rock_path(input)
+    }
+
+    fn part1(rock_paths: &Self::Input) -> u32 {
+        let sim_depth = *rock_paths.iter().flatten().map(|(_, y)| y).max().unwrap();
+        let mut grid = Grid::new(SIM_WIDTH, sim_depth + 1, Content::Empty);
+        initial_fill(&mut grid, rock_paths);
+        let mut sand_count = 0;
+        while let Some(pos) = get_next_sand_pos(&grid, sim_depth, SOURCE) {
+            grid[pos] = Content::Sand;
+            sand_count += 1;
+        }
+        sand_count
+    }
+
+    fn part2(rock_paths: &Self::Input) -> u32 {
+        let floor_depth = *rock_paths.iter().flatten().map(|(_, y)| y).max().unwrap() + 2_usize;
+        let mut grid = Grid::new(SIM_WIDTH, floor_depth + 1, Content::Empty);
+        initial_fill(&mut grid, rock_paths);
+        grid.row_mut(floor_depth).fill(Content::Rock);
+        let mut sand_count = 0;
+        loop {
+            let pos = get_next_sand_pos(&grid, floor_depth, SOURCE).expect("sand falled");
+            grid[pos] = Content::Sand;
+            sand_count += 1;
+            if pos == SOURCE {
+                break;
+            }
+        }
+        sand_count
+    }
+}
+
+/// Pours sand grain by grain until it flows into the abyss, drawing the columns spanned by
+/// the rocks after each grain.
+impl Animated for Day14 {
+    fn frames(rock_paths: Self::Input) -> Frames {
+        let sim_depth = *rock_paths.iter().flatten().map(|(_, y)| y).max().unwrap();
+        let mut grid = Grid::new(SIM_WIDTH, sim_depth + 1, Content::Empty);
+        initial_fill(&mut grid, &rock_paths);
+        // Resting sand is always held above the rocks, one column either side at most
+        let (left, right) = match rock_paths.iter().flatten().map(|(x, _)| x).minmax() {
+            MinMaxResult::MinMax(&min, &max) => (min - X_OFFSET - 1, max - X_OFFSET + 1),
+            MinMaxResult::OneElement(&x) => (x - X_OFFSET - 1, x - X_OFFSET + 1),
+            MinMaxResult::NoElements => unreachable!("parsing requires a rock path"),
+        };
+        let (left, right) = (left.min(SOURCE.col), right.max(SOURCE.col));
+        let view = move |grid: &Grid<Content>, grains: u32| {
+            let cells = grid.subgrid(Coord::new(0, left), right - left + 1, grid.height());
+            Frame::new(format!("Grains of sand: {}", grains), &cells)
+        };
+        let first = view(&grid, 0);
+        let mut grains = 0;
+        let pour = iter::from_fn(move || {
+            let pos = get_next_sand_pos(&grid, sim_depth, SOURCE)?;
+            grid[pos] = Content::Sand;
+            grains += 1;
+            Some(view(&grid, grains))
+        });
+        Box::new(iter::once(first).chain(pour))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day14::parse(&get_example(Day14::DAY)).unwrap();
+        assert_eq!(Day14::part1(&input), 24);
+        assert_eq!(Day14::part2(&input), 93);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day14::parse(&get_example(Day14::DAY)).unwrap();
+        let frames = Day14::frames(input).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 25);
+        let last = frames.last().unwrap();
+        assert_eq!(last.caption, "Grains of sand: 24");
+        assert_eq!(last.glyphs.width(), 12);
+        assert_eq!(last.glyphs.row(0)[7], "🟥");
+    }
+}
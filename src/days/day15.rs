@@ -0,0 +1,134 @@
+use parse_display::FromStr;
+use rayon::prelude::*;
+
+use crate::{
+    error::parse_lines,
+    geometry::Point2,
+    interval::{Interval, IntervalSet},
+    progress::Progress,
+    solution::{Result, Solution},
+};
+
+#[derive(PartialEq, Debug)]
+pub struct Pair {
+    sensor: Point2,
+    beacon: Point2,
+}
+
+/// A line of the report, as it is written.
+#[derive(FromStr, Debug)]
+#[display("Sensor at x={sensor_x}, y={sensor_y}: closest beacon is at x={beacon_x}, y={beacon_y}")]
+struct Report {
+    sensor_x: i32,
+    sensor_y: i32,
+    beacon_x: i32,
+    beacon_y: i32,
+}
+
+impl From<Report> for Pair {
+    fn from(report: Report) -> Self {
+        Pair {
+            sensor: Point2::new(report.sensor_x, report.sensor_y),
+            beacon: Point2::new(report.beacon_x, report.beacon_y),
+        }
+    }
+}
+
+fn get_pairs(input: &str) -> Result<Vec<Pair>> {
+    parse_lines(Day15::DAY, input, |l| Ok(l.parse::<Report>()?.into()))
+}
+
+/// The positions of the row `y` that a sensor sees closer than its beacon
+fn covered_on_line(pairs: &[Pair], y: i32) -> IntervalSet {
+    pairs
+        .iter()
+        .filter_map(|pair| {
+            let closest_beacon_distance = pair.sensor.manhattan(pair.beacon);
+            let target_line_distance = pair.sensor.manhattan(Point2::new(pair.sensor.x, y));
+            let dx = closest_beacon_distance.checked_sub(target_line_distance)? as i64;
+            let x = pair.sensor.x as i64;
+            Interval::new(x - dx, x + dx)
+        })
+        .collect()
+}
+
+/// Number of positions of the row `target_y` where no beacon can be
+fn count_excluded_positions(pairs: &[Pair], target_y: i32) -> u64 {
+    let occupied = pairs
+        .iter()
+        .flat_map(|p| [p.sensor, p.beacon])
+        .filter(|p| p.y == target_y)
+        .map(|p| Interval::point(p.x as i64))
+        .collect();
+    covered_on_line(pairs, target_y)
+        .difference(&occupied)
+        .coverage()
+}
+
+/// Tuning frequency of the only uncovered position with coordinates in `0..=max_coordinate`
+fn find_tuning_frequency(pairs: &[Pair], max_coordinate: i32) -> u64 {
+    let progress = Progress::new(max_coordinate as u64 + 1, "Scanning rows");
+    let bounds = Interval::new(0, max_coordinate as i64).expect("coordinates are not negative");
+    let result = (0..=max_coordinate)
+        .into_par_iter()
+        .map(|y| {
+            progress.inc(1);
+            let hole = covered_on_line(pairs, y).gaps_within(bounds).next();
+            hole.map(|gap| gap.start() as u64 * 4000000 + y as u64)
+        })
+        .find_first(|r: &Option<u64>| r.is_some());
+
+    if let Some(r) = result {
+        return r.unwrap();
+    }
+    panic!("Hole not found");
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Pair>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_pairs(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> u64 {
+        count_excluded_positions(pairs, 2_000_000)
+    }
+
+    fn part2(pairs: &Self::Input) -> u64 {
+        find_tuning_frequency(pairs, 4_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_parsing() {
+        assert_eq!(
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+                .parse::<Report>()
+                .map(Pair::from)
+                .unwrap(),
+            Pair {
+                sensor: Point2::new(2, 18),
+                beacon: Point2::new(-2, 15),
+            }
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day15::parse(&get_example(Day15::DAY)).unwrap();
+        assert_eq!(count_excluded_positions(&input, 10), 26);
+        assert_eq!(find_tuning_frequency(&input, 20), 56000011);
+    }
+}
@@ -0,0 +1,334 @@
+use std::iter;
+
+use crate::{
+    animation::{Animated, Frame, Frames, Glyph, Palette, Rgb},
+    error::ParseError,
+    grid::{Coord, Grid},
+    solution::{Result, Solution},
+    trace,
+};
+
+const WIDTH: usize = 7;
+/// Rows of the top of the chamber drawn in traces and animations.
+const VIEW_ROWS: usize = 20;
+
+#[derive(Debug)]
+pub enum Move {
+    Left,
+    Right,
+}
+
+fn get_moves(input: &str) -> Result<Vec<Move>> {
+    let line = input.lines().next().unwrap_or_default();
+    let error = |message| ParseError::new(Day17::DAY, 1, line, message);
+    if line.is_empty() {
+        return Err(error("empty jet pattern".to_string()).into());
+    }
+    line.chars()
+        .map(|c| match c {
+            '>' => Ok(Move::Right),
+            '<' => Ok(Move::Left),
+            _ => Err(error(format!("unknown jet {:?}", c)).into()),
+        })
+        .collect()
+}
+
+/// Whether the cells of `rock` overlap the chamber when its bottom left corner is at `(x, y)`.
+fn collides(chamber: &Grid<bool>, rock: &Grid<bool>, x: usize, y: usize) -> bool {
+    rock.iter().any(|(c, &r)| {
+        r && chamber
+            .get(Coord::new(y + c.row, x + c.col))
+            .is_some_and(|&c| c)
+    })
+}
+
+fn is_dx_ok(chamber: &Grid<bool>, rock: &Grid<bool>, x: usize, y: usize, dx: i32) -> bool {
+    let rock_w = rock.width();
+    // Horizontal boundaries
+    if x as i32 + dx < 0 || (x + rock_w - 1) as i32 + dx >= WIDTH as i32 {
+        return false;
+    }
+    let new_x = (x as i32 + dx) as usize;
+    !collides(chamber, rock, new_x, y)
+}
+
+/// Lets `rock` fall from its starting position, pushed by the next jets, until it settles.
+fn drop_rock<'a>(
+    chamber: &mut Grid<bool>,
+    rock: &Grid<bool>,
+    jets: &mut impl Iterator<Item = &'a Move>,
+) {
+    let (mut x, mut y) = (2, 3 + chamber.height()); // x of the left y of the bottom
+    loop {
+        match jets.next().expect("jets repeat forever") {
+            Move::Left if is_dx_ok(chamber, rock, x, y, -1) => x -= 1,
+            Move::Right if is_dx_ok(chamber, rock, x, y, 1) => x += 1,
+            _ => (),
+        }
+
+        if is_step_down_ok(chamber, rock, x, y) {
+            y -= 1;
+        } else {
+            settle(chamber, rock, x, y);
+            break;
+        }
+    }
+}
+
+fn is_step_down_ok(chamber: &Grid<bool>, rock: &Grid<bool>, x: usize, y: usize) -> bool {
+    // Ground check
+    if y == 0 {
+        return false;
+    }
+    !collides(chamber, rock, x, y - 1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+    Wall,
+}
+
+impl Glyph for Cell {
+    fn glyph(&self) -> &'static str {
+        match self {
+            Cell::Air => "⬜",
+            Cell::Rock => "🟨",
+            Cell::Wall => "⬛",
+        }
+    }
+}
+
+impl Palette for Cell {
+    fn colour(&self) -> Rgb {
+        match self {
+            Cell::Air => [245, 245, 240],
+            Cell::Rock => [235, 190, 60],
+            Cell::Wall => [40, 40, 45],
+        }
+    }
+}
+
+/// The top `rows` rows of the chamber between its walls, ending with the floor when it is in
+/// view, and padded with air above so that every view has the same size.
+fn chamber_view(chamber: &Grid<bool>, rows: usize) -> Grid<Cell> {
+    let walled = |line: &[bool]| {
+        let cells = line.iter().map(|&r| if r { Cell::Rock } else { Cell::Air });
+        iter::once(Cell::Wall)
+            .chain(cells)
+            .chain(iter::once(Cell::Wall))
+            .collect::<Vec<_>>()
+    };
+    let padding = rows.saturating_sub(chamber.height() + 1);
+    let view = iter::repeat_n(walled(&[false; WIDTH]), padding)
+        .chain(chamber.rows().rev().map(walled))
+        .chain(iter::once(vec![Cell::Wall; WIDTH + 2]))
+        .take(rows);
+    Grid::from_rows(view)
+}
+
+/// The top of the chamber once `rocks` rocks have settled.
+fn chamber_frame(chamber: &Grid<bool>, rocks: usize) -> Frame {
+    let caption = format!("Rocks: {}, height: {}", rocks, chamber.height());
+    Frame::new(caption, &chamber_view(chamber, VIEW_ROWS))
+}
+
+/// Drops `rock` into the chamber at `(x, y)`, growing the chamber to fit it.
+fn settle(chamber: &mut Grid<bool>, rock: &Grid<bool>, x: usize, y: usize) {
+    if y + rock.height() > chamber.height() {
+        chamber.resize_rows(y + rock.height(), false);
+    }
+    for (c, &r) in rock.iter() {
+        chamber[Coord::new(y + c.row, x + c.col)] |= r;
+    }
+}
+
+/// The five rock shapes, in falling order, with their bottom row first.
+fn get_rocks() -> [Grid<bool>; 5] {
+    [
+        vec!["####"],
+        vec![".#.", "###", ".#."],
+        vec!["..#", "..#", "###"],
+        vec!["#", "#", "#", "#"],
+        vec!["##", "##"],
+    ]
+    .map(|r| Grid::from_rows(r.iter().rev().map(|l| l.chars().map(|c| c == '#'))))
+}
+
+#[derive(Debug)]
+struct Pattern {
+    start: usize,
+    len: usize,
+}
+
+fn find_consecutive_pattern<T>(data: &[T]) -> Option<Pattern>
+where
+    T: Eq,
+{
+    let n = data.len();
+    for start in 0..data.len() {
+        let duplicate_start_candidates = data[start..]
+            .iter()
+            .enumerate()
+            .filter(|(i, e)| *i > start && i + (i - start + 1) < n && **e == data[start])
+            .map(|(i, _)| i);
+        for dup_start_candidate in duplicate_start_candidates {
+            let len = dup_start_candidate - start;
+            if len == 0 {
+                continue;
+            }
+            if (0..len).all(|i| data[start + i] == data[dup_start_candidate + i]) {
+                return Some(Pattern { start, len });
+            }
+        }
+    }
+    None
+}
+
+fn get_relevant_last_lines(chamber: &Grid<bool>) -> Vec<[bool; WIDTH]> {
+    let mut out = vec![];
+    let mut union = [false; WIDTH];
+    for line in chamber.rows().rev() {
+        out.push(line.try_into().expect("chamber rows are WIDTH wide"));
+        for (uv, lv) in union.iter_mut().zip(line) {
+            *uv |= *lv;
+        }
+        if union.iter().all(|e| *e) {
+            break;
+        }
+    }
+    out
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct AddedRockState {
+    relevant_lines: Vec<[bool; WIDTH]>,
+    added_height: usize,
+    rock_index: usize,
+}
+
+const TARGET: usize = 1000000000000;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Vec<Move>;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_moves(input)
+    }
+
+    fn part1(moves: &Self::Input) -> u32 {
+        let rocks = get_rocks();
+        let mut moves_iter = moves.iter().cycle();
+        let mut chamber = Grid::new(WIDTH, 0, false);
+
+        for rock_index in 0..2022 {
+            let rock = &rocks[rock_index % rocks.len()];
+            drop_rock(&mut chamber, rock, &mut moves_iter);
+            trace!(Frames, "{}\n", chamber_frame(&chamber, rock_index + 1));
+        }
+        chamber.height() as u32
+    }
+
+    fn part2(moves: &Self::Input) -> usize {
+        let rocks = get_rocks();
+
+        let mut moves_iter = moves.iter().cycle();
+        let mut added_by_rock = vec![];
+
+        let mut chamber = Grid::new(WIDTH, 0, false);
+        for rock_index in 0.. {
+            let rock = &rocks[rock_index % rocks.len()];
+            let initial_chamber_size = chamber.height();
+            drop_rock(&mut chamber, rock, &mut moves_iter);
+            added_by_rock.push(AddedRockState {
+                added_height: chamber.height() - initial_chamber_size,
+                relevant_lines: get_relevant_last_lines(&chamber),
+                rock_index: rock_index % rocks.len(),
+            });
+
+            if let Some(pattern) = find_consecutive_pattern(&added_by_rock) {
+                trace!(Summary, "Found pattern={:#?}", pattern);
+                let added_before: usize = added_by_rock[0..pattern.start]
+                    .iter()
+                    .map(|a| a.added_height)
+                    .sum();
+                let pattern_weight: usize = added_by_rock
+                    [pattern.start..pattern.start + pattern.len]
+                    .iter()
+                    .map(|a| a.added_height)
+                    .sum();
+                let pattern_occurences = (TARGET - pattern.start) / pattern.len;
+                let remaining_rocks = (TARGET - pattern.start) % pattern.len;
+                let remaining_rocks_weight: usize = added_by_rock
+                    [pattern.start..pattern.start + remaining_rocks]
+                    .iter()
+                    .map(|a| a.added_height)
+                    .sum();
+
+                return added_before + pattern_occurences * pattern_weight + remaining_rocks_weight;
+            }
+        }
+        panic!("Pattern not found");
+    }
+}
+
+/// Drops the 2022 rocks of the first part, one frame per rock.
+impl Animated for Day17 {
+    fn frames(moves: Self::Input) -> Frames {
+        let rocks = get_rocks();
+        let mut chamber = Grid::new(WIDTH, 0, false);
+        let mut jet = 0;
+        let first = chamber_frame(&chamber, 0);
+        let falls = (0..2022).map(move |rock_index| {
+            // Jets are counted rather than borrowed, the moves being owned by the iterator
+            let mut jets = moves[jet % moves.len()..]
+                .iter()
+                .chain(moves.iter().cycle())
+                .inspect(|_| jet += 1);
+            drop_rock(&mut chamber, &rocks[rock_index % rocks.len()], &mut jets);
+            chamber_frame(&chamber, rock_index + 1)
+        });
+        Box::new(iter::once(first).chain(falls))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day17::parse(&get_example(Day17::DAY)).unwrap();
+        assert_eq!(Day17::part1(&input), 3068);
+        assert_eq!(Day17::part2(&input), 1514285714288);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day17::parse(&get_example(Day17::DAY)).unwrap();
+        let frames = Day17::frames(input).take(3).collect::<Vec<_>>();
+        assert_eq!(frames[2].caption, "Rocks: 2, height: 4");
+        assert!(frames
+            .iter()
+            .all(|f| (f.glyphs.width(), f.glyphs.height()) == (WIDTH + 2, VIEW_ROWS)));
+        let top = frames[2].glyphs.rows().skip(VIEW_ROWS - 5);
+        assert_eq!(
+            top.map(|row| row.concat()).collect::<Vec<_>>(),
+            [
+                "⬛⬜⬜⬜🟨⬜⬜⬜⬛",
+                "⬛⬜⬜🟨🟨🟨⬜⬜⬛",
+                "⬛⬜⬜⬜🟨⬜⬜⬜⬛",
+                "⬛⬜⬜🟨🟨🟨🟨⬜⬛",
+                "⬛⬛⬛⬛⬛⬛⬛⬛⬛"
+            ]
+        );
+    }
+}
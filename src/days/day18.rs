@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::{
+    animation::{Animated, Frame, Frames, Glyph, Palette, Rgb},
+    error::parse_lines,
+    geometry::Point3,
+    grid::Grid,
+    search::bfs,
+    solution::{Result, Solution},
+};
+
+/// The box around the droplet, with a layer of water on every side.
+fn water_box(positions: &[Point3]) -> (Point3, Point3) {
+    let min = Point3::new(
+        positions.iter().map(|p| p.x).min().unwrap_or_default() - 1,
+        positions.iter().map(|p| p.y).min().unwrap_or_default() - 1,
+        positions.iter().map(|p| p.z).min().unwrap_or_default() - 1,
+    );
+    let max = Point3::new(
+        positions.iter().map(|p| p.x).max().unwrap_or_default() + 1,
+        positions.iter().map(|p| p.y).max().unwrap_or_default() + 1,
+        positions.iter().map(|p| p.z).max().unwrap_or_default() + 1,
+    );
+    (min, max)
+}
+
+/// The cubes reached by the water flowing from `start`, without leaving the box between
+/// `min` and `max`.
+fn flood(lava: &HashSet<Point3>, start: Point3, (min, max): (Point3, Point3)) -> HashSet<Point3> {
+    let in_water = |p: &Point3| p.within(min, max) && !lava.contains(p);
+    let water = bfs([start], |p| p.neighbours6().filter(in_water), |_| false);
+    water.distances().map(|(&p, _)| p).collect()
+}
+
+/// Counts the lava faces reached by the water flowing from `start`, without leaving the box
+/// between `min` and `max`.
+fn explore_water(lava: &HashSet<Point3>, start: Point3, bounds: (Point3, Point3)) -> u32 {
+    flood(lava, start, bounds)
+        .iter()
+        .flat_map(|p| p.neighbours6())
+        .filter(|n| lava.contains(n))
+        .count() as u32
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cube {
+    Water,
+    Lava,
+    /// Air trapped inside the droplet
+    Pocket,
+}
+
+impl Glyph for Cube {
+    fn glyph(&self) -> &'static str {
+        match self {
+            Cube::Water => "🟦",
+            Cube::Lava => "🟧",
+            Cube::Pocket => "⬛️",
+        }
+    }
+}
+
+impl Palette for Cube {
+    fn colour(&self) -> Rgb {
+        match self {
+            Cube::Water => [110, 170, 230],
+            Cube::Lava => [230, 110, 30],
+            Cube::Pocket => [30, 30, 35],
+        }
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Point3>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(Self::DAY, input, |l| {
+            let (x, y, z) = l
+                .split(',')
+                .map(|s| s.parse::<i32>())
+                .collect::<std::result::Result<Vec<_>, _>>()?
+                .into_iter()
+                .collect_tuple()
+                .ok_or("expected three coordinates")?;
+            Ok(Point3::new(x, y, z))
+        })
+    }
+
+    fn part1(positions: &Self::Input) -> u32 {
+        let lava: HashSet<Point3> = positions.iter().copied().collect();
+        positions
+            .iter()
+            .flat_map(|p| p.neighbours6())
+            .filter(|n| !lava.contains(n))
+            .count() as u32
+    }
+
+    fn part2(positions: &Self::Input) -> u32 {
+        let lava: HashSet<Point3> = positions.iter().copied().collect();
+        let bounds = water_box(positions);
+        explore_water(&lava, bounds.0, bounds)
+    }
+}
+
+/// Slices the droplet along z, showing the water around it and the air pockets inside.
+impl Animated for Day18 {
+    fn frames(positions: Self::Input) -> Frames {
+        let lava: HashSet<Point3> = positions.iter().copied().collect();
+        let (min, max) = water_box(&positions);
+        let water = flood(&lava, min, (min, max));
+        Box::new((min.z..=max.z).map(move |z| {
+            let slice = Grid::from_rows((min.y..=max.y).map(|y| {
+                let (lava, water) = (&lava, &water);
+                (min.x..=max.x).map(move |x| {
+                    let p = Point3::new(x, y, z);
+                    if lava.contains(&p) {
+                        Cube::Lava
+                    } else if water.contains(&p) {
+                        Cube::Water
+                    } else {
+                        Cube::Pocket
+                    }
+                })
+            }));
+            Frame::new(format!("z = {}", z), &slice)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day18::parse(&get_example(Day18::DAY)).unwrap();
+        assert_eq!(Day18::part1(&input), 64);
+        assert_eq!(Day18::part2(&input), 58);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day18::parse(&get_example(Day18::DAY)).unwrap();
+        let frames = Day18::frames(input).collect::<Vec<_>>();
+        // z goes from 1 to 6, plus a layer of water on each side
+        assert_eq!(frames.len(), 8);
+        assert_eq!(frames[0].caption, "z = 0");
+        // The only pocket of the example is at 2,2,5
+        let pockets = frames
+            .iter()
+            .map(|f| f.glyphs.iter().filter(|&(_, &g)| g == "⬛️").count())
+            .collect::<Vec<_>>();
+        assert_eq!(pockets, [0, 0, 0, 0, 0, 1, 0, 0]);
+    }
+}
@@ -0,0 +1,254 @@
+use int_enum::IntEnum;
+use itertools::Itertools;
+use regex::Regex;
+
+use std::vec;
+use strum::EnumIter;
+use strum::IntoEnumIterator;
+
+use crate::{
+    error::parse_lines,
+    progress::Progress,
+    solution::{Result, Solution},
+    trace,
+};
+
+#[derive(Debug, IntEnum, Clone, Copy, PartialEq, Eq, EnumIter)]
+#[repr(u8)]
+enum Resource {
+    Ore = 0,
+    Clay = 1,
+    Obsidian = 2,
+    Geode = 3,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct Resources([u32; 4]);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Blueprint([Resources; 4]);
+
+fn get_blueprints(input: &str) -> Result<Vec<Blueprint>> {
+    let re = Regex::new(r"Blueprint (?P<i>\d+): .+? costs (?P<ore_ore>\d+) .+? costs (?P<clay_ore>\d+) .+? costs (?P<obsidian_ore>\d+) ore and (?P<obsidian_clay>\d+) clay.+? costs (?P<geode_ore>\d+) ore and (?P<geode_obsidian>\d+) obsidian\.").unwrap();
+    parse_lines(Day19::DAY, input, |line| {
+        let caps = re
+            .captures(line)
+            .ok_or("expected a blueprint description")?;
+        let cost = |name: &str| caps[name].parse::<u32>();
+        Ok(Blueprint([
+            Resources([cost("ore_ore")?, 0, 0, 0]),
+            Resources([cost("clay_ore")?, 0, 0, 0]),
+            Resources([cost("obsidian_ore")?, cost("obsidian_clay")?, 0, 0]),
+            Resources([cost("geode_ore")?, 0, cost("geode_obsidian")?, 0]),
+        ]))
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct State {
+    production: Resources,
+    resources: Resources,
+    finished_minute: u32,
+    geode_produced: u32,
+    produced: Vec<(u32, Resource, Resources, [Option<u32>; 4])>,
+}
+
+impl State {
+    fn advance_time(&self, dt: u32) -> State {
+        State {
+            resources: Resources(std::array::from_fn(|i| {
+                self.resources.0[i] + self.production.0[i] * dt
+            })),
+            finished_minute: self.finished_minute + dt,
+            ..self.clone()
+        }
+    }
+
+    fn get_time_to_produce(&self, blueprint: &Blueprint, kind: Resource) -> Option<u32> {
+        let cost = blueprint.0[kind.int_value() as usize];
+        if cost
+            .0
+            .iter()
+            .zip(self.production.0.iter())
+            .any(|(c, p)| *c > 0 && *p == 0)
+        {
+            return None;
+        }
+        Some(
+            self.resources
+                .0
+                .iter()
+                .zip(cost.0.iter())
+                .zip(self.production.0.iter())
+                .filter(|((r, c), p)| c > r && **p != 0)
+                .map(|((r, c), p)| ((*c - *r) as f32 / *p as f32).ceil() as u32)
+                .max()
+                .unwrap_or(0),
+        )
+    }
+    fn produce_robot(&self, blueprint: &Blueprint, kind: Resource, max_time: u32) -> Option<State> {
+        let cost = blueprint.0[kind.int_value() as usize];
+        if self
+            .resources
+            .0
+            .iter()
+            .zip(cost.0.iter())
+            .all(|(r, c)| *r >= *c)
+        {
+            let new_finished_minute = self.finished_minute + 1;
+            let mut new_production = self.production;
+            let mut geode_produced = self.geode_produced;
+            if kind != Resource::Geode {
+                new_production.0[kind.int_value() as usize] += 1;
+            } else {
+                geode_produced += max_time.saturating_sub(new_finished_minute);
+            }
+            let mut new_resources = self.advance_time(1).resources;
+            for (i, c) in cost.0.iter().enumerate() {
+                new_resources.0[i] -= *c;
+            }
+
+            let new_state = State {
+                production: new_production,
+                resources: new_resources,
+                geode_produced,
+                produced: self.produced.clone(),
+                finished_minute: new_finished_minute,
+            };
+            let produced = self
+                .produced
+                .clone()
+                .into_iter()
+                .chain([(
+                    new_finished_minute,
+                    kind,
+                    new_resources,
+                    [
+                        new_state.get_time_to_produce(blueprint, Resource::Ore),
+                        new_state.get_time_to_produce(blueprint, Resource::Clay),
+                        new_state.get_time_to_produce(blueprint, Resource::Obsidian),
+                        new_state.get_time_to_produce(blueprint, Resource::Geode),
+                    ],
+                )])
+                .collect_vec();
+
+            Some(State {
+                produced,
+                ..new_state
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// The most geodes `blueprint` can open in `max_time` minutes, with the state reaching them.
+fn max_geodes(blueprint: &Blueprint, max_time: u32) -> (u32, State) {
+    let mut stack = vec![];
+    stack.push(State {
+        resources: Resources([1, 0, 0, 0]),
+        geode_produced: 0,
+        production: Resources([1, 0, 0, 0]),
+        finished_minute: 1,
+        produced: vec![(
+            0,
+            Resource::Ore,
+            Resources([0, 0, 0, 0]),
+            [None, None, None, None],
+        )],
+    });
+    let mut max_geodes = 0;
+    let mut max_state = stack[0].clone();
+    while let Some(state) = stack.pop() {
+        if state.geode_produced >= max_geodes {
+            max_geodes = state.geode_produced;
+            max_state = state.clone();
+        }
+        // Even a geode robot finished every minute from the next one cannot do better
+        let remaining = max_time.saturating_sub(state.finished_minute);
+        if state.geode_produced + remaining * remaining.saturating_sub(1) / 2 <= max_geodes {
+            continue;
+        }
+        for kind in Resource::iter().rev() {
+            let kind_index = kind.int_value() as usize;
+            if kind != Resource::Geode
+                && state.production.0[kind_index]
+                    >= *blueprint.0.map(|c| c.0[kind_index]).iter().max().unwrap()
+            {
+                continue;
+            }
+            if let Some(dt) = state.get_time_to_produce(blueprint, kind) {
+                if let Some(new_state) = state
+                    .advance_time(dt)
+                    .produce_robot(blueprint, kind, max_time)
+                {
+                    if new_state.finished_minute >= max_time {
+                        continue;
+                    }
+                    stack.push(new_state);
+                }
+            }
+        }
+    }
+    (max_geodes, max_state)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Vec<Blueprint>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_blueprints(input)
+    }
+
+    fn part1(blueprints: &Self::Input) -> u32 {
+        const MAX_TIME: u32 = 24;
+        let progress = Progress::new(blueprints.len() as u64, "Blueprints");
+        let mut quality_levels = 0;
+        for (index, blueprint) in blueprints.iter().enumerate() {
+            trace!(Summary, "Blueprint={:?}", blueprint);
+            let (geodes, state) = max_geodes(blueprint, MAX_TIME);
+            trace!(Steps, "{:#?}", state);
+            progress.inc(1);
+            quality_levels += (index as u32 + 1) * geodes;
+        }
+        quality_levels
+    }
+
+    fn part2(blueprints: &Self::Input) -> u32 {
+        const MAX_TIME: u32 = 32;
+        let progress = Progress::new(blueprints.len().min(3) as u64, "Blueprints");
+        let mut product = 1;
+        for blueprint in blueprints.iter().take(3) {
+            trace!(Summary, "Blueprint={:?}", blueprint);
+            let (geodes, state) = max_geodes(blueprint, MAX_TIME);
+            trace!(Steps, "{:#?}", state);
+            progress.inc(1);
+            product *= geodes;
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day19::parse(&get_example(Day19::DAY)).unwrap();
+        assert_eq!(Day19::part1(&input), 33);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = Day19::parse(&get_example(Day19::DAY)).unwrap();
+        assert_eq!(Day19::part2(&input), 56 * 62);
+    }
+}
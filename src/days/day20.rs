@@ -0,0 +1,150 @@
+use std::collections::LinkedList;
+
+use itertools::Itertools;
+
+use crate::{
+    error::parse_lines,
+    solution::{Result, Solution},
+};
+
+fn apply_transformation(data: LinkedList<i64>) -> LinkedList<i64> {
+    let mut data: LinkedList<(usize, i64)> = data.into_iter().enumerate().collect();
+    let n = data.len();
+    for i in 0..n {
+        let index = data
+            .iter()
+            .find_position(|(j, _)| *j == i)
+            .expect("not found")
+            .0;
+        let mut after = data.split_off(index);
+        let elt = after.pop_front().unwrap();
+        data.append(&mut after);
+        let new_index = ((index as i64 + elt.1 - 1).rem_euclid(n as i64 - 1) + 1) as usize;
+        let mut rest = data.split_off(new_index);
+        data.push_back(elt);
+        data.append(&mut rest);
+    }
+    data.into_iter().map(|(_i, e)| e).collect()
+}
+
+fn apply_transformation_step(data: LinkedList<(usize, i64)>) -> LinkedList<(usize, i64)> {
+    let mut data = data.clone();
+    let n = data.len();
+    for i in 0..n {
+        let index = data
+            .iter()
+            .find_position(|(j, _)| *j == i)
+            .expect("not found")
+            .0;
+        let mut tail = data.split_off(index);
+        let elt = tail.pop_front().expect("index is in bounds");
+        data.append(&mut tail);
+        let new_index = ((index as i64 + elt.1 - 1).rem_euclid(n as i64 - 1) + 1) as usize;
+        let mut rest = data.split_off(new_index);
+        data.push_back(elt);
+        data.append(&mut rest);
+    }
+    data.into_iter().collect()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<i64>;
+    type Output1 = u32;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(Self::DAY, input, |l| Ok(l.parse()?))
+    }
+
+    fn part1(numbers: &Self::Input) -> u32 {
+        let data: LinkedList<i64> = numbers.iter().copied().collect();
+        let data = apply_transformation(data);
+        let data_vec = data.iter().collect_vec();
+        let zero_index = data
+            .iter()
+            .find_position(|e| **e == 0)
+            .expect("not found")
+            .0;
+        [1000, 2000, 3000]
+            .iter()
+            .map(|i| *data_vec[(zero_index + *i) % data_vec.len()])
+            .sum::<i64>() as u32
+    }
+
+    fn part2(numbers: &Self::Input) -> i64 {
+        const FACTOR: i64 = 811589153;
+        let mut data: LinkedList<(usize, i64)> =
+            numbers.iter().map(|n| n * FACTOR).enumerate().collect();
+        for _ in 0..10 {
+            data = apply_transformation_step(data);
+        }
+        let data_vec = data.iter().collect_vec();
+        let zero_index = data
+            .iter()
+            .find_position(|(_i, e)| *e == 0)
+            .expect("not found")
+            .0;
+        [1000, 2000, 3000]
+            .iter()
+            .map(|i| data_vec[(zero_index + *i) % data_vec.len()].1)
+            .sum::<i64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    /// The mixed list is circular, so it only matters up to a rotation
+    fn assert_same_cycle(actual: LinkedList<i64>, expected: &[i64]) {
+        let actual = actual.into_iter().collect_vec();
+        assert!(
+            (0..expected.len().max(1)).any(|k| {
+                let mut rotated = actual.clone();
+                rotated.rotate_left(k);
+                rotated == expected
+            }),
+            "{:?} is not a rotation of {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_transformation() {
+        assert_same_cycle(apply_transformation(LinkedList::from([0, -1])), &[-1, 0]);
+        assert_same_cycle(apply_transformation(LinkedList::from([1, 0])), &[0, 1]);
+        assert_same_cycle(
+            apply_transformation(LinkedList::from([2, 0, 0])),
+            &[0, 0, 2],
+        );
+        assert_same_cycle(
+            apply_transformation(LinkedList::from([-2, 0, 0, 0])),
+            &[0, 0, -2, 0],
+        );
+        assert_same_cycle(
+            apply_transformation(LinkedList::from([3, 0, 0])),
+            &[0, 3, 0],
+        );
+    }
+
+    #[test]
+    fn test_example_mixing() {
+        assert_eq!(
+            apply_transformation(LinkedList::from([1, 2, -3, 3, -2, 0, 4])),
+            LinkedList::from([1, 2, -3, 4, 0, 3, -2])
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day20::parse(&get_example(Day20::DAY)).unwrap();
+        assert_eq!(Day20::part1(&input), 3);
+        assert_eq!(Day20::part2(&input), 1623178306);
+    }
+}
@@ -1,27 +1,12 @@
-#![feature(test)]
-extern crate test;
+use std::collections::HashMap;
 
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
+use crate::{
+    error::{parse_lines, ParseError},
+    solution::{Result, Solution},
 };
 
-use itertools::Itertools;
-
-const DAY: &str = "21";
-
-fn get_input() -> Vec<String> {
-    let path = format!("inputs/day{}.txt", DAY);
-    let file = File::open(path).expect("Could not open file");
-    BufReader::new(file)
-        .lines()
-        .filter_map(|line| line.ok())
-        .collect_vec()
-}
-
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Add(String, String),
     Sub(String, String),
     Mul(String, String),
@@ -29,6 +14,40 @@ enum Operation {
     Scalar(i64),
 }
 
+fn get_monkeys(input: &str) -> Result<HashMap<String, Operation>> {
+    let lines = parse_lines(Day21::DAY, input, |line| {
+        let (name, op) = line.split_once(": ").ok_or("missing ': '")?;
+        let operation = match op.split(' ').collect::<Vec<_>>()[..] {
+            [a, "+", b] => Operation::Add(a.into(), b.into()),
+            [a, "-", b] => Operation::Sub(a.into(), b.into()),
+            [a, "*", b] => Operation::Mul(a.into(), b.into()),
+            [a, "/", b] => Operation::Div(a.into(), b.into()),
+            [n] => Operation::Scalar(n.parse()?),
+            _ => return Err("unknown operation".into()),
+        };
+        Ok((name.to_string(), operation))
+    })?;
+    let monkeys: HashMap<String, Operation> = lines.into_iter().collect();
+    for (index, line) in input.lines().enumerate() {
+        let name = line.split_once(": ").map_or(line, |(name, _)| name);
+        use Operation::*;
+        if let Add(a, b) | Sub(a, b) | Mul(a, b) | Div(a, b) = &monkeys[name] {
+            if let Some(unknown) = [a, b].into_iter().find(|m| !monkeys.contains_key(*m)) {
+                let message = format!("unknown monkey {}", unknown);
+                return Err(ParseError::new(Day21::DAY, index + 1, line, message).into());
+            }
+        }
+    }
+    for name in ["root", "humn"] {
+        if !monkeys.contains_key(name) {
+            let message = format!("missing monkey {}", name);
+            let line = input.lines().count() + 1;
+            return Err(ParseError::new(Day21::DAY, line, "", message).into());
+        }
+    }
+    Ok(monkeys)
+}
+
 fn get_operation_value(table: &HashMap<String, Operation>, op: &Operation) -> i64 {
     use Operation::*;
     match op {
@@ -56,34 +75,6 @@ fn get_operation_value(table: &HashMap<String, Operation>, op: &Operation) -> i6
     }
 }
 
-fn part1(lines: &Vec<String>) -> i64 {
-    let mut monkeys: HashMap<String, Operation> = HashMap::new();
-    for line in lines {
-        let (name, op) = line.split_once(": ").unwrap();
-        let operation = if op.contains("+") {
-            let (a, b) = op.split_once(" + ").unwrap();
-            Operation::Add(a.into(), b.into())
-        } else if op.contains("-") {
-            let (a, b) = op.split_once(" - ").unwrap();
-            Operation::Sub(a.into(), b.into())
-        } else if op.contains("*") {
-            let (a, b) = op.split_once(" * ").unwrap();
-            Operation::Mul(a.into(), b.into())
-        } else if op.contains("/") {
-            let (a, b) = op.split_once(" / ").unwrap();
-            Operation::Div(a.into(), b.into())
-        } else {
-            Operation::Scalar(op.parse().unwrap())
-        };
-        monkeys.insert(name.to_string(), operation);
-    }
-    let root = monkeys
-        .get(&"root".to_string())
-        .expect("root not found")
-        .clone();
-    get_operation_value(&monkeys, root)
-}
-
 fn populate_values(
     table: &HashMap<String, Operation>,
     values: &mut HashMap<String, Option<i64>>,
@@ -185,72 +176,54 @@ fn explore(
     }
 }
 
-fn part2(lines: &Vec<String>) -> i64 {
-    let mut monkeys: HashMap<String, Operation> = HashMap::new();
-    for line in lines {
-        let (name, op) = line.split_once(": ").unwrap();
-        let operation = if op.contains("+") {
-            let (a, b) = op.split_once(" + ").unwrap();
-            Operation::Add(a.into(), b.into())
-        } else if op.contains("-") {
-            let (a, b) = op.split_once(" - ").unwrap();
-            Operation::Sub(a.into(), b.into())
-        } else if op.contains("*") {
-            let (a, b) = op.split_once(" * ").unwrap();
-            Operation::Mul(a.into(), b.into())
-        } else if op.contains("/") {
-            let (a, b) = op.split_once(" / ").unwrap();
-            Operation::Div(a.into(), b.into())
-        } else {
-            Operation::Scalar(op.parse().unwrap())
-        };
-        monkeys.insert(name.to_string(), operation);
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = HashMap<String, Operation>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_monkeys(input)
     }
-    let root = monkeys
-        .get(&"root".to_string())
-        .expect("root not found")
-        .clone();
 
-    let mut values: HashMap<String, Option<i64>> = HashMap::new();
-    populate_values(&monkeys, &mut values, &"root".to_string());
-    let (a, b) = match root {
-        Operation::Add(a, b) => (a, b),
-        _ => panic!("no"),
-    };
-    if let Some(va) = values.get(a).unwrap() {
-        let expected = va;
-        explore(&monkeys, &values, b, *expected).unwrap()
-    } else if let Some(vb) = values.get(b).unwrap() {
-        let expected = vb;
-        explore(&monkeys, &values, a, *expected).unwrap()
-    } else {
-        0
+    fn part1(monkeys: &Self::Input) -> i64 {
+        let root = monkeys.get(&"root".to_string()).expect("root not found");
+        get_operation_value(monkeys, root)
     }
-}
 
-fn main() {
-    let input = get_input();
-    let p1_total = part1(&input);
-    println!("Part1 total: {}", p1_total);
-    let p2_total = part2(&input);
-    println!("Part2 total: {}", p2_total);
+    fn part2(monkeys: &Self::Input) -> i64 {
+        let root = monkeys.get(&"root".to_string()).expect("root not found");
+
+        let mut values: HashMap<String, Option<i64>> = HashMap::new();
+        populate_values(monkeys, &mut values, &"root".to_string());
+        let (a, b) = match root {
+            Operation::Add(a, b) => (a, b),
+            _ => panic!("no"),
+        };
+        if let Some(va) = values.get(a).unwrap() {
+            let expected = va;
+            explore(monkeys, &values, b, *expected).unwrap()
+        } else if let Some(vb) = values.get(b).unwrap() {
+            let expected = vb;
+            explore(monkeys, &values, a, *expected).unwrap()
+        } else {
+            0
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use test::{black_box, Bencher};
-
-    #[bench]
-    fn bench_part1(b: &mut Bencher) {
-        let lines: Vec<String> = get_input();
-        b.iter(|| part1(black_box(&lines)));
-    }
+    use crate::solution::get_example;
 
-    #[bench]
-    fn bench_part2(b: &mut Bencher) {
-        let lines: Vec<String> = get_input();
-        b.iter(|| part2(black_box(&lines)));
+    #[test]
+    fn test_example() {
+        let input = Day21::parse(&get_example(Day21::DAY)).unwrap();
+        assert_eq!(Day21::part1(&input), 152);
+        assert_eq!(Day21::part2(&input), 301);
     }
 }
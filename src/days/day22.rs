@@ -0,0 +1,616 @@
+use std::iter;
+
+use itertools::Itertools;
+
+use crate::{
+    animation::{Animated, Frame, Frames, Glyph, Palette, Rgb},
+    error::ParseError,
+    geometry::Direction4::{self, *},
+    grid::{Coord, Grid},
+    solution::{Attempt, Result, Solution},
+    trace,
+};
+
+#[derive(Debug)]
+pub enum Step {
+    Move(u32),
+    RotateCW,
+    RotateCCW,
+}
+
+/// Open tiles are `Some(false)`, walls `Some(true)` and the void around the map `None`
+pub type Map = Grid<Option<bool>>;
+
+/// The supported ways of unfolding the cube on the map: the one of the puzzle example
+/// (3 faces high) and the one of the actual input (4 faces high).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeNet {
+    Example,
+    Input,
+}
+
+impl CubeNet {
+    /// Where each face lies on the map, as (row, column) in faces.
+    fn face_origins(self) -> [(usize, usize); 6] {
+        match self {
+            CubeNet::Example => [(0, 2), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)],
+            CubeNet::Input => [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)],
+        }
+    }
+
+    /// The width and height of the net, in faces.
+    fn shape(self) -> (usize, usize) {
+        match self {
+            CubeNet::Example => (4, 3),
+            CubeNet::Input => (3, 4),
+        }
+    }
+
+    /// The net of the map, when the map is the six faces of a supported net and nothing else.
+    fn detect(map: &Map) -> Option<CubeNet> {
+        [CubeNet::Example, CubeNet::Input].into_iter().find(|net| {
+            let (columns, rows) = net.shape();
+            let n = map.height() / rows;
+            let fits = n > 0 && map.width() == columns * n && map.height() == rows * n;
+            let faces = net.face_origins();
+            let matches = || {
+                map.iter().all(|(coord, tile)| {
+                    let face = (coord.row / n, coord.col / n);
+                    tile.is_some() == faces.contains(&face)
+                })
+            };
+            fits && matches()
+        })
+    }
+}
+
+/// A tile of the map, as drawn with the path walked on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PathTile {
+    Void,
+    Open,
+    Wall,
+    /// Walked on, with the last facing on it
+    Walked(Direction4),
+}
+
+impl Glyph for PathTile {
+    fn glyph(&self) -> &'static str {
+        match self {
+            PathTile::Walked(Down) => "👇",
+            PathTile::Walked(Right) => "👉",
+            PathTile::Walked(Up) => "👆",
+            PathTile::Walked(Left) => "👈",
+            PathTile::Wall => "⬛️",
+            PathTile::Open => "⬜",
+            PathTile::Void => "🔳",
+        }
+    }
+}
+
+impl Palette for PathTile {
+    fn colour(&self) -> Rgb {
+        match self {
+            PathTile::Walked(_) => [210, 50, 40],
+            PathTile::Wall => [40, 40, 45],
+            PathTile::Open => [245, 245, 240],
+            PathTile::Void => [150, 150, 155],
+        }
+    }
+}
+
+/// The map with the path walked on it.
+fn path_view(map: &Map, visited: &Grid<Option<Direction4>>) -> Grid<PathTile> {
+    Grid::from_rows(map.rows().zip(visited.rows()).map(|(tiles, facings)| {
+        tiles
+            .iter()
+            .zip(facings)
+            .map(|(tile, facing)| match (facing, tile) {
+                (Some(facing), _) => PathTile::Walked(*facing),
+                (None, Some(true)) => PathTile::Wall,
+                (None, Some(false)) => PathTile::Open,
+                (None, None) => PathTile::Void,
+            })
+            .collect::<Vec<_>>()
+    }))
+}
+
+/// A walk along the path: the password at its end, and the facings left on the tiles.
+type Walk = (usize, Grid<Option<Direction4>>);
+
+fn get_data(input: &str) -> Result<(Map, Vec<Step>, Option<CubeNet>)> {
+    let lines = input.lines().collect_vec();
+    let error = |index: usize, message: String| {
+        ParseError::new(
+            Day22::DAY,
+            index + 1,
+            lines.get(index).unwrap_or(&""),
+            message,
+        )
+    };
+    let map_lines = lines.iter().take_while(|l| !l.is_empty()).collect_vec();
+    let max_length = map_lines
+        .iter()
+        .map(|l| l.len())
+        .max()
+        .ok_or_else(|| error(0, "missing map".to_string()))?;
+    let mut occupied = Grid::new(max_length, map_lines.len(), None);
+    for (i, l) in map_lines.iter().enumerate() {
+        for (j, c) in l.chars().enumerate() {
+            occupied[Coord::new(i, j)] = match c {
+                ' ' => None,
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => return Err(error(i, format!("unmatched map character {:?}", c)).into()),
+            }
+        }
+    }
+    let path_index = map_lines.len() + 1;
+    let path_str = lines
+        .get(path_index)
+        .ok_or_else(|| error(path_index, "missing path".to_string()))?;
+    let mut path = vec![];
+    let mut buffer = vec![];
+    for c in path_str.chars() {
+        if c.is_ascii_digit() {
+            buffer.push(c);
+        } else {
+            if let Ok(v) = buffer.iter().join("").parse::<u32>() {
+                path.push(Step::Move(v));
+                buffer.clear();
+            }
+            match c {
+                'R' => path.push(Step::RotateCW),
+                'L' => path.push(Step::RotateCCW),
+                _ => return Err(error(path_index, format!("unknown rotation {:?}", c)).into()),
+            }
+        }
+    }
+    if let Ok(v) = buffer.iter().join("").parse::<u32>() {
+        path.push(Step::Move(v));
+        buffer.clear();
+    }
+    // Only the walk on the cube needs a supported net
+    let net = CubeNet::detect(&occupied);
+    Ok((occupied, path, net))
+}
+
+fn get_new_direction(direction: Direction4, step: &Step) -> Direction4 {
+    match step {
+        Step::Move(_) => direction,
+        Step::RotateCW => direction.rotate_cw(),
+        Step::RotateCCW => direction.rotate_ccw(),
+    }
+}
+
+/// The facing part of the password.
+fn facing_score(direction: Direction4) -> usize {
+    match direction {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    }
+}
+#[derive(Debug)]
+struct PathSlice {
+    start: usize,
+    end: usize,
+}
+
+impl PathSlice {
+    /// The span of a row or column between the void on either side of the map.
+    fn new<'a>(cells: impl Iterator<Item = &'a Option<bool>>) -> Self {
+        let mut it = cells
+            .enumerate()
+            .filter(|(_, e)| e.is_some())
+            .map(|(i, _)| i);
+        let start = it.next().unwrap();
+        let end = it.last().unwrap_or(start) + 1;
+        PathSlice { start, end }
+    }
+}
+
+fn get_starting_position(occupancy: &Map) -> (usize, usize) {
+    (
+        0_usize,
+        occupancy
+            .row(0)
+            .iter()
+            .position(|e| *e == Some(false))
+            .unwrap(),
+    )
+}
+
+#[derive(Debug)]
+struct Link {
+    in_dir: Direction4,
+    in_face: usize,
+    inverted: bool,
+}
+
+#[derive(Debug)]
+struct Face {
+    occupancy: Grid<bool>,
+    /// Position of the face's top left corner on the map
+    origin: Coord,
+    links: [Option<Link>; 4],
+}
+
+fn get_face_starting_position(occupancy: &Grid<bool>) -> (usize, usize) {
+    (0_usize, occupancy.row(0).iter().position(|e| !e).unwrap())
+}
+
+impl Face {
+    fn from_occupancy(occupancy: &Map, start: (usize, usize), n: usize) -> Face {
+        let origin = Coord::new(start.0, start.1);
+        Face {
+            occupancy: occupancy.subgrid(origin, n, n).map(|e| e.unwrap()),
+            origin,
+            links: Default::default(),
+        }
+    }
+
+    fn to_global_pos(&self, (i, j): (usize, usize)) -> Coord {
+        Coord::new(self.origin.row + i, self.origin.col + j)
+    }
+
+    fn add_link(
+        &mut self,
+        out_dir: Direction4,
+        in_face: usize,
+        in_dir: Direction4,
+        inverted: bool,
+    ) {
+        self.links[out_dir as usize] = Some(Link {
+            in_dir,
+            in_face,
+            inverted,
+        })
+    }
+
+    fn pass_through(&self, dir: Direction4, index: usize) -> ((usize, usize), usize, Direction4) {
+        let n = self.occupancy.height();
+        let link = self.links[dir as usize].as_ref().unwrap();
+        let new_index = if link.inverted { n - index - 1 } else { index };
+        let face_pos = match link.in_dir {
+            Up => (n - 1, new_index),
+            Right => (new_index, 0),
+            Down => (0, new_index),
+            Left => (new_index, n - 1),
+        };
+        (face_pos, link.in_face, link.in_dir)
+    }
+}
+
+/// Cuts the map into the six faces of `net` and links their edges.
+fn fold_cube(occupancy: &Map, net: CubeNet) -> (usize, [Face; 6]) {
+    let n = occupancy.height() / net.shape().1;
+    let mut faces = net
+        .face_origins()
+        .map(|(row, col)| Face::from_occupancy(occupancy, (row * n, col * n), n));
+    match net {
+        CubeNet::Example => {
+            faces[0].add_link(Up, 1, Down, true);
+            faces[0].add_link(Right, 5, Left, true);
+            faces[0].add_link(Down, 3, Down, false);
+            faces[0].add_link(Left, 2, Down, false);
+
+            faces[1].add_link(Up, 0, Down, true);
+            faces[1].add_link(Right, 2, Right, false);
+            faces[1].add_link(Down, 4, Up, true);
+            faces[1].add_link(Left, 5, Up, true);
+
+            faces[2].add_link(Up, 0, Right, false);
+            faces[2].add_link(Right, 3, Right, false);
+            faces[2].add_link(Down, 4, Right, true);
+            faces[2].add_link(Left, 1, Left, false);
+
+            faces[3].add_link(Up, 0, Up, false);
+            faces[3].add_link(Right, 5, Down, true);
+            faces[3].add_link(Down, 4, Down, false);
+            faces[3].add_link(Left, 2, Left, false);
+
+            faces[4].add_link(Up, 3, Up, false);
+            faces[4].add_link(Right, 5, Right, false);
+            faces[4].add_link(Down, 1, Up, true);
+            faces[4].add_link(Left, 2, Up, true);
+
+            faces[5].add_link(Up, 3, Left, true);
+            faces[5].add_link(Right, 0, Left, true);
+            faces[5].add_link(Down, 1, Right, true);
+            faces[5].add_link(Left, 4, Left, false);
+        }
+        CubeNet::Input => {
+            faces[0].add_link(Up, 5, Right, false);
+            faces[0].add_link(Right, 1, Right, false);
+            faces[0].add_link(Down, 2, Down, false);
+            faces[0].add_link(Left, 3, Right, true);
+
+            faces[1].add_link(Up, 5, Up, false);
+            faces[1].add_link(Right, 4, Left, true);
+            faces[1].add_link(Down, 2, Left, false);
+            faces[1].add_link(Left, 0, Left, false);
+
+            faces[2].add_link(Up, 0, Up, false);
+            faces[2].add_link(Right, 1, Up, false);
+            faces[2].add_link(Down, 4, Down, false);
+            faces[2].add_link(Left, 3, Down, false);
+
+            faces[3].add_link(Up, 2, Right, false);
+            faces[3].add_link(Right, 4, Right, false);
+            faces[3].add_link(Down, 5, Down, false);
+            faces[3].add_link(Left, 0, Right, true);
+
+            faces[4].add_link(Up, 2, Up, false);
+            faces[4].add_link(Right, 1, Left, true);
+            faces[4].add_link(Down, 5, Left, false);
+            faces[4].add_link(Left, 3, Left, false);
+
+            faces[5].add_link(Up, 3, Up, false);
+            faces[5].add_link(Right, 4, Up, false);
+            faces[5].add_link(Down, 1, Down, false);
+            faces[5].add_link(Left, 0, Down, false);
+        }
+    }
+    (n, faces)
+}
+
+/// Walks `path` on the flat map, wrapping around each row and column.
+fn walk_map(occupancy: &Map, path: &[Step]) -> Walk {
+    let (width, height) = (occupancy.width(), occupancy.height());
+    let start = get_starting_position(occupancy);
+    let line_slices = (0..height)
+        .map(|i| PathSlice::new(occupancy.row(i).iter()))
+        .collect_vec();
+    let column_slices = (0..width)
+        .map(|j| PathSlice::new(occupancy.column(j)))
+        .collect_vec();
+
+    let mut visited = Grid::new(width, height, None);
+    let mut pos = start;
+    let mut direction = Right;
+    visited[Coord::new(pos.0, pos.1)] = Some(direction);
+    for step in path {
+        if let Step::Move(n) = *step {
+            match direction {
+                Right => {
+                    let slice = &line_slices[pos.0];
+                    for _ in 0..n {
+                        let new_j = if pos.1 + 1 >= slice.end {
+                            slice.start
+                        } else {
+                            pos.1 + 1
+                        };
+                        if let Some(true) = occupancy[Coord::new(pos.0, new_j)] {
+                            break;
+                        }
+                        pos = (pos.0, new_j);
+                        visited[Coord::new(pos.0, pos.1)] = Some(direction);
+                    }
+                }
+                Left => {
+                    let slice = &line_slices[pos.0];
+                    for _ in 0..n {
+                        let new_j = if pos.1 < slice.start + 1 {
+                            slice.end - 1
+                        } else {
+                            pos.1 - 1
+                        };
+                        if let Some(true) = occupancy[Coord::new(pos.0, new_j)] {
+                            break;
+                        }
+                        pos = (pos.0, new_j);
+                        visited[Coord::new(pos.0, pos.1)] = Some(direction);
+                    }
+                }
+                Down => {
+                    let slice = &column_slices[pos.1];
+                    for _ in 0..n {
+                        let new_i = if pos.0 + 1 >= slice.end {
+                            slice.start
+                        } else {
+                            pos.0 + 1
+                        };
+                        if let Some(true) = occupancy[Coord::new(new_i, pos.1)] {
+                            break;
+                        }
+                        pos = (new_i, pos.1);
+                        visited[Coord::new(pos.0, pos.1)] = Some(direction);
+                    }
+                }
+                Up => {
+                    let slice = &column_slices[pos.1];
+                    for _ in 0..n {
+                        let new_i = if pos.0 < slice.start + 1 {
+                            slice.end - 1
+                        } else {
+                            pos.0 - 1
+                        };
+                        if let Some(true) = occupancy[Coord::new(new_i, pos.1)] {
+                            break;
+                        }
+                        pos = (new_i, pos.1);
+                        visited[Coord::new(pos.0, pos.1)] = Some(direction);
+                    }
+                }
+            }
+        } else {
+            direction = get_new_direction(direction, step);
+        }
+
+        trace!(Steps, "{:?} {:?}", pos, direction);
+    }
+    let password = 1000 * (pos.0 + 1) + 4 * (pos.1 + 1) + facing_score(direction);
+    (password, visited)
+}
+
+/// Walks `path` on the map folded into a cube.
+fn walk_cube(occupancy: &Map, path: &[Step], net: CubeNet) -> Walk {
+    let (width, height) = (occupancy.width(), occupancy.height());
+    let (n, faces) = fold_cube(occupancy, net);
+    let mut direction = Right;
+    let mut face_index = 0usize;
+    let mut pos = get_face_starting_position(&faces[face_index].occupancy);
+
+    let mut visited = Grid::new(width, height, None);
+    visited[faces[face_index].to_global_pos(pos)] = Some(direction);
+    for step in path {
+        match step {
+            Step::Move(dist) => {
+                for _ in 0..*dist {
+                    let mut next_pos = pos;
+                    let mut next_face_index = face_index;
+                    let mut next_direction = direction;
+                    match direction {
+                        Up => {
+                            if pos.0 == 0 {
+                                (next_pos, next_face_index, next_direction) =
+                                    faces[face_index].pass_through(Up, pos.1)
+                            } else {
+                                next_pos.0 = pos.0 - 1;
+                            }
+                        }
+                        Right => {
+                            if pos.1 == n - 1 {
+                                (next_pos, next_face_index, next_direction) =
+                                    faces[face_index].pass_through(Right, pos.0)
+                            } else {
+                                next_pos.1 = pos.1 + 1;
+                            }
+                        }
+                        Down => {
+                            if pos.0 == n - 1 {
+                                (next_pos, next_face_index, next_direction) =
+                                    faces[face_index].pass_through(Down, pos.1)
+                            } else {
+                                next_pos.0 = pos.0 + 1;
+                            }
+                        }
+                        Left => {
+                            if pos.1 == 0 {
+                                (next_pos, next_face_index, next_direction) =
+                                    faces[face_index].pass_through(Left, pos.0)
+                            } else {
+                                next_pos.1 = pos.1 - 1;
+                            }
+                        }
+                    }
+                    if faces[next_face_index].occupancy[Coord::new(next_pos.0, next_pos.1)] {
+                        break;
+                    }
+                    pos = next_pos;
+                    face_index = next_face_index;
+                    direction = next_direction;
+                    visited[faces[face_index].to_global_pos(pos)] = Some(direction);
+                }
+            }
+            Step::RotateCW | Step::RotateCCW => direction = get_new_direction(direction, step),
+        }
+        let global_pos = faces[face_index].to_global_pos(pos);
+        trace!(Steps, "{} {:?}", global_pos, direction);
+    }
+    let final_pos = faces[face_index].to_global_pos(pos);
+    let password = 1000 * (final_pos.row + 1) + 4 * (final_pos.col + 1) + facing_score(direction);
+    (password, visited)
+}
+
+const UNSUPPORTED_NET: &str = "the map does not fold into a supported cube net";
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = (Map, Vec<Step>, Option<CubeNet>);
+    type Output1 = usize;
+    type Output2 = Attempt<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_data(input)
+    }
+
+    fn part1((map, path, _): &Self::Input) -> usize {
+        let (password, visited) = walk_map(map, path);
+        trace!(Frames, "{}", Frame::new("", &path_view(map, &visited)));
+        password
+    }
+
+    fn part2((map, path, net): &Self::Input) -> Attempt<usize> {
+        let Some(net) = net else {
+            return Attempt::Unsolvable(UNSUPPORTED_NET.to_string());
+        };
+        let (password, visited) = walk_cube(map, path, *net);
+        trace!(Frames, "{}", Frame::new("", &path_view(map, &visited)));
+        Attempt::Solved(password)
+    }
+}
+
+/// The path walked on the map, then on the cube.
+impl Animated for Day22 {
+    fn frames((map, path, net): Self::Input) -> Frames {
+        let (password, visited) = walk_map(&map, &path);
+        let flat = Frame::new(
+            format!("On the map, password: {}", password),
+            &path_view(&map, &visited),
+        );
+        let cube = iter::once_with(move || match net {
+            Some(net) => {
+                let (password, visited) = walk_cube(&map, &path, net);
+                let caption = format!("On the cube, password: {}", password);
+                Frame::new(caption, &path_view(&map, &visited))
+            }
+            None => {
+                let unvisited = Grid::new(map.width(), map.height(), None);
+                let caption = format!("On the cube: {}", UNSUPPORTED_NET);
+                Frame::new(caption, &path_view(&map, &unvisited))
+            }
+        });
+        Box::new(iter::once(flat).chain(cube))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day22::parse(&get_example(Day22::DAY)).unwrap();
+        assert_eq!(Day22::part1(&input), 6032);
+        assert_eq!(Day22::part2(&input), Attempt::Solved(5031));
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day22::parse(&get_example(Day22::DAY)).unwrap();
+        let frames = Day22::frames(input).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].caption, "On the map, password: 6032");
+        assert_eq!(frames[1].caption, "On the cube, password: 5031");
+        assert_eq!(frames[0].glyphs.row(0)[8..12].concat(), "👉👉👉⬛️");
+    }
+
+    #[test]
+    fn test_unsupported_net() {
+        let example = get_example(Day22::DAY);
+        assert_eq!(Day22::parse(&example).unwrap().2, Some(CubeNet::Example));
+        // A fourth face in the top row leaves the net with seven faces
+        let seven_faces = example.replacen("        ...#", "....    ...#", 1);
+        let input = Day22::parse(&seven_faces).unwrap();
+        assert_eq!(input.2, None);
+        // The flat walk does not care about the net
+        let (password, _) = walk_map(&input.0, &input.1);
+        assert_eq!(Day22::part1(&input), password);
+        assert_eq!(
+            Day22::part2(&input),
+            Attempt::Unsolvable(UNSUPPORTED_NET.to_string())
+        );
+        let frames = Day22::frames(input).collect::<Vec<_>>();
+        assert_eq!(
+            frames[1].caption,
+            format!("On the cube: {}", UNSUPPORTED_NET)
+        );
+    }
+}
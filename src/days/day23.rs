@@ -0,0 +1,244 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+};
+
+use itertools::{Itertools, MinMaxResult};
+
+use crate::{
+    animation::{Animated, Frame, Frames, Glyph, Palette, Rgb},
+    error::ParseError,
+    geometry::{Direction8, Point2},
+    grid::Grid,
+    solution::{Result, Solution},
+    trace,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Ground,
+    Elf,
+}
+
+impl Glyph for Tile {
+    fn glyph(&self) -> &'static str {
+        match self {
+            Tile::Ground => "⬜️",
+            Tile::Elf => "🟥",
+        }
+    }
+}
+
+impl Palette for Tile {
+    fn colour(&self) -> Rgb {
+        match self {
+            Tile::Ground => [245, 245, 240],
+            Tile::Elf => [210, 50, 40],
+        }
+    }
+}
+
+/// The corners of the smallest rectangle holding every elf.
+fn bounds(elves: &HashSet<Point2>) -> (Point2, Point2) {
+    let (xmin, xmax) = elves
+        .iter()
+        .map(|p| p.x)
+        .minmax()
+        .into_option()
+        .unwrap_or_default();
+    let (ymin, ymax) = elves
+        .iter()
+        .map(|p| p.y)
+        .minmax()
+        .into_option()
+        .unwrap_or_default();
+    (Point2::new(xmin, ymin), Point2::new(xmax, ymax))
+}
+
+/// The elves in the rectangle from `min` to `max`, both included.
+fn elves_view(elves: &HashSet<Point2>, (min, max): (Point2, Point2)) -> Grid<Tile> {
+    Grid::from_rows((min.y..=max.y).map(|y| {
+        (min.x..=max.x).map(move |x| {
+            if elves.contains(&Point2::new(x, y)) {
+                Tile::Elf
+            } else {
+                Tile::Ground
+            }
+        })
+    }))
+}
+
+/// The directions elves consider, in the order of the first round.
+const PROPOSALS: [Direction8; 4] = [
+    Direction8::North,
+    Direction8::South,
+    Direction8::West,
+    Direction8::East,
+];
+
+/// Plays one round: elves with a neighbour propose a move, and those alone on their
+/// destination take it. Returns whether any elf made a proposal.
+fn play_round(elves: &mut HashSet<Point2>, round: usize) -> bool {
+    let mut moves = HashMap::new();
+    let mut duplicate_destinations = HashSet::new();
+    for &elf in elves.iter() {
+        if elf.neighbours8().all(|p| !elves.contains(&p)) {
+            continue;
+        }
+        let proposal = PROPOSALS
+            .iter()
+            .cycle()
+            .skip(round)
+            .take(4)
+            .find(|&&direction| {
+                [direction.rotate_ccw(), direction, direction.rotate_cw()]
+                    .iter()
+                    .all(|&d| !elves.contains(&(elf + d)))
+            });
+        if let Some(&direction) = proposal {
+            let new_pos = elf + direction;
+            if moves.insert(new_pos, elf).is_some() {
+                duplicate_destinations.insert(new_pos);
+            }
+        }
+    }
+    for (dest, base) in &moves {
+        if !duplicate_destinations.contains(dest) {
+            elves.remove(base);
+            elves.insert(*dest);
+        }
+    }
+    !moves.is_empty()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = HashSet<Point2>;
+    type Output1 = i32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut elves = HashSet::new();
+        for (i, l) in input.lines().enumerate() {
+            for (j, c) in l.chars().enumerate() {
+                match c {
+                    '#' => {
+                        elves.insert(Point2::new(j as i32, i as i32));
+                    }
+                    '.' => (),
+                    _ => {
+                        let message = format!("unknown tile {:?}", c);
+                        return Err(ParseError::new(Self::DAY, i + 1, l, message).into());
+                    }
+                }
+            }
+        }
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> i32 {
+        let mut elves = elves.clone();
+        trace!(
+            Frames,
+            "{}\n",
+            Frame::new("== Initial State ==", &elves_view(&elves, bounds(&elves)))
+        );
+        for round in 0..10 {
+            play_round(&mut elves, round);
+            let caption = format!("== End of Round {} ==", round + 1);
+            let view = elves_view(&elves, bounds(&elves));
+            trace!(Frames, "{}\n", Frame::new(caption, &view));
+        }
+
+        match (
+            elves.iter().map(|p| p.x).minmax(),
+            elves.iter().map(|p| p.y).minmax(),
+        ) {
+            (MinMaxResult::MinMax(xmin, xmax), MinMaxResult::MinMax(ymin, ymax)) => {
+                (xmax - xmin + 1) * (ymax - ymin + 1) - elves.len() as i32
+            }
+            _ => panic!("nothing"),
+        }
+    }
+
+    fn part2(elves: &Self::Input) -> usize {
+        let mut elves = elves.clone();
+        let mut first_with_no_moving = None;
+        for round in 0.. {
+            let moved = play_round(&mut elves, round);
+            trace!(Steps, "Round {}: elves moved: {}", round + 1, moved);
+            if !moved {
+                if let Some(n) = first_with_no_moving {
+                    if round - n >= 4 {
+                        return n + 1;
+                    }
+                } else {
+                    first_with_no_moving = Some(round);
+                }
+            } else {
+                first_with_no_moving = None;
+            }
+        }
+        panic!("nope");
+    }
+}
+
+/// Spreads the elves round by round, until none of them moves. Every frame shows the whole
+/// area the elves ever cover, found by playing the rounds a first time.
+impl Animated for Day23 {
+    fn frames(mut elves: Self::Input) -> Frames {
+        let (mut min, mut max) = bounds(&elves);
+        let mut spread = elves.clone();
+        for round in 0.. {
+            let moved = play_round(&mut spread, round);
+            let (low, high) = bounds(&spread);
+            min = Point2::new(min.x.min(low.x), min.y.min(low.y));
+            max = Point2::new(max.x.max(high.x), max.y.max(high.y));
+            if !moved {
+                break;
+            }
+        }
+        let first = Frame::new("Initial state", &elves_view(&elves, (min, max)));
+        let (mut round, mut moved) = (0, true);
+        let rounds = iter::from_fn(move || {
+            if !moved {
+                return None;
+            }
+            moved = play_round(&mut elves, round);
+            round += 1;
+            let caption = format!("End of round {}", round);
+            Some(Frame::new(caption, &elves_view(&elves, (min, max))))
+        });
+        Box::new(iter::once(first).chain(rounds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day23::parse(&get_example(Day23::DAY)).unwrap();
+        assert_eq!(Day23::part1(&input), 110);
+        assert_eq!(Day23::part2(&input), 20);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day23::parse(&get_example(Day23::DAY)).unwrap();
+        let frames = Day23::frames(input).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 21);
+        assert_eq!(frames[20].caption, "End of round 20");
+        let size = (frames[0].glyphs.width(), frames[0].glyphs.height());
+        assert!(frames
+            .iter()
+            .all(|f| (f.glyphs.width(), f.glyphs.height()) == size));
+        // The frames cover where the elves spread, beyond their initial 7x7 area
+        assert!(size.0 > 7 && size.1 > 7);
+    }
+}
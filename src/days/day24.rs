@@ -0,0 +1,242 @@
+use crate::{
+    animation::{Animated, Frame, Frames, Glyph, Palette, Rgb},
+    error::ParseError,
+    geometry::{Direction4, Point2},
+    grid::Grid,
+    search::{bfs, Search},
+    solution::{Result, Solution},
+};
+
+#[derive(Debug, Hash, Clone, Copy, Eq, PartialEq)]
+struct State {
+    pos: Point2,
+    t: u32,
+}
+
+struct Blizzard {
+    initial_position: Point2,
+    direction: Direction4,
+}
+
+pub struct Valley {
+    blizzards: Vec<Blizzard>,
+    height: i32,
+    width: i32,
+}
+
+impl Valley {
+    fn entrance(&self) -> Point2 {
+        Point2::new(1, 0)
+    }
+
+    fn exit(&self) -> Point2 {
+        Point2::new(self.width - 2, self.height - 1)
+    }
+
+    /// Whether `pos` is the entrance, the exit or inside the walls.
+    fn is_open(&self, pos: Point2) -> bool {
+        pos == self.entrance()
+            || pos == self.exit()
+            || (pos.x > 0 && pos.x < self.width - 1 && pos.y > 0 && pos.y < self.height - 1)
+    }
+
+    /// Where `bliz` is at minute `t`.
+    fn blizzard_position(&self, bliz: &Blizzard, t: u32) -> Point2 {
+        let moved = bliz.initial_position + bliz.direction.delta() * t as i32;
+        // Blizzards wrap around inside the walls
+        Point2::new(
+            1 + (moved.x - 1).rem_euclid(self.width - 2),
+            1 + (moved.y - 1).rem_euclid(self.height - 2),
+        )
+    }
+
+    fn get_blizzard_occupancy_map(&self, t: u32) -> Grid<bool> {
+        let mut out = Grid::new(self.width as usize, self.height as usize, false);
+        for bliz in &self.blizzards {
+            let pos = self.blizzard_position(bliz, t);
+            out[pos.to_coord().expect("wrapped inside the valley")] = true;
+        }
+        out
+    }
+
+    /// The valley at minute `t`, with the expedition at `expedition`.
+    fn view(&self, t: u32, expedition: Point2) -> Grid<Tile> {
+        let mut tiles = Grid::new(self.width as usize, self.height as usize, Tile::Wall);
+        for coord in tiles.coords().collect::<Vec<_>>() {
+            if self.is_open(coord.into()) {
+                tiles[coord] = Tile::Ground;
+            }
+        }
+        for bliz in &self.blizzards {
+            let coord = self.blizzard_position(bliz, t).to_coord().expect("inside");
+            tiles[coord] = match tiles[coord] {
+                Tile::Ground => Tile::Blizzard(bliz.direction),
+                Tile::Blizzard(_) => Tile::Blizzards(2),
+                Tile::Blizzards(n) => Tile::Blizzards(n + 1),
+                Tile::Wall | Tile::Expedition => unreachable!("blizzards stay inside"),
+            };
+        }
+        tiles[expedition.to_coord().expect("inside")] = Tile::Expedition;
+        tiles
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Ground,
+    Blizzard(Direction4),
+    /// Several blizzards crossing
+    Blizzards(u8),
+    Expedition,
+}
+
+impl Glyph for Tile {
+    fn glyph(&self) -> &'static str {
+        const COUNTS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        match self {
+            Tile::Wall => "#",
+            Tile::Ground => ".",
+            Tile::Blizzard(Direction4::Up) => "^",
+            Tile::Blizzard(Direction4::Right) => ">",
+            Tile::Blizzard(Direction4::Down) => "v",
+            Tile::Blizzard(Direction4::Left) => "<",
+            Tile::Blizzards(n) => COUNTS[(*n as usize).min(9)],
+            Tile::Expedition => "E",
+        }
+    }
+}
+
+impl Palette for Tile {
+    fn colour(&self) -> Rgb {
+        match self {
+            Tile::Wall => [40, 40, 45],
+            Tile::Ground => [245, 245, 240],
+            Tile::Blizzard(_) => [150, 200, 235],
+            Tile::Blizzards(_) => [70, 130, 200],
+            Tile::Expedition => [60, 170, 70],
+        }
+    }
+}
+
+/// The fastest crossings from `start` to `dest`, leaving at minute `start_t`.
+fn cross(valley: &Valley, start: Point2, dest: Point2, start_t: u32) -> Search<State, usize> {
+    let start = State {
+        pos: start,
+        t: start_t,
+    };
+    // States are expanded in time order, so each map is computed once
+    let mut blizzard = valley.get_blizzard_occupancy_map(start_t + 1);
+    let mut blizzard_t = start_t + 1;
+    let successors = |&State { pos, t }: &State| {
+        if blizzard_t != t + 1 {
+            blizzard = valley.get_blizzard_occupancy_map(t + 1);
+            blizzard_t = t + 1;
+        }
+        [pos]
+            .into_iter()
+            .chain(pos.neighbours4())
+            .filter(|&pos| valley.is_open(pos) && !pos.to_coord().is_some_and(|c| blizzard[c]))
+            .map(|pos| State { pos, t: t + 1 })
+            .collect::<Vec<_>>()
+    };
+    bfs([start], successors, |state| state.pos == dest)
+}
+
+fn get_arrival_time(valley: &Valley, start: Point2, dest: Point2, start_t: u32) -> u32 {
+    cross(valley, start, dest, start_t)
+        .goal()
+        .expect("Path not found")
+        .t
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Valley;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let tiles = Grid::parse(Self::DAY, input, |c| match c {
+            '^' => Ok(Some(Direction4::Up)),
+            '<' => Ok(Some(Direction4::Left)),
+            '>' => Ok(Some(Direction4::Right)),
+            'v' => Ok(Some(Direction4::Down)),
+            '#' | '.' => Ok(None),
+            _ => Err(format!("unknown tile {:?}", c).into()),
+        })?;
+        if tiles.height() < 3 || tiles.width() < 3 {
+            return Err(ParseError::new(Self::DAY, 1, "", "the valley is too small").into());
+        }
+        let blizzards = tiles
+            .iter()
+            .filter_map(|(coord, direction)| {
+                Some(Blizzard {
+                    initial_position: coord.into(),
+                    direction: (*direction)?,
+                })
+            })
+            .collect();
+        Ok(Valley {
+            blizzards,
+            height: tiles.height() as i32,
+            width: tiles.width() as i32,
+        })
+    }
+
+    fn part1(valley: &Self::Input) -> u32 {
+        get_arrival_time(valley, valley.entrance(), valley.exit(), 0)
+    }
+
+    fn part2(valley: &Self::Input) -> u32 {
+        let t1 = get_arrival_time(valley, valley.entrance(), valley.exit(), 0);
+        let t2 = get_arrival_time(valley, valley.exit(), valley.entrance(), t1);
+        get_arrival_time(valley, valley.entrance(), valley.exit(), t2)
+    }
+}
+
+/// Follows the expedition minute by minute on the fastest way to the exit.
+impl Animated for Day24 {
+    fn frames(valley: Self::Input) -> Frames {
+        let path = cross(&valley, valley.entrance(), valley.exit(), 0)
+            .goal_path()
+            .expect("Path not found");
+        Box::new(
+            path.into_iter().map(move |State { pos, t }| {
+                Frame::new(format!("Minute {}", t), &valley.view(t, pos))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day24::parse(&get_example(Day24::DAY)).unwrap();
+        assert_eq!(Day24::part1(&input), 18);
+        assert_eq!(Day24::part2(&input), 54);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day24::parse(&get_example(Day24::DAY)).unwrap();
+        let frames = Day24::frames(input).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 19);
+        assert_eq!(
+            frames[0].to_string(),
+            "Minute 0\n#E######\n#>>.<^<#\n#.<..<<#\n#>v.><>#\n#<^v^^>#\n######.#"
+        );
+        // The blizzards of minute 1, as drawn in the puzzle
+        assert_eq!(frames[1].glyphs.row(3).concat(), "#>2.22.#");
+        let last = frames.last().unwrap();
+        assert_eq!(last.caption, "Minute 18");
+        assert_eq!(last.glyphs.row(5).concat(), "######E#");
+    }
+}
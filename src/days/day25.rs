@@ -0,0 +1,69 @@
+use itertools::Itertools;
+
+use crate::{
+    error::parse_lines,
+    solution::{Result, Solution},
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Vec<String>;
+    type Output1 = String;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(Self::DAY, input, |line| {
+            match line.chars().find(|c| !"=-012".contains(*c)) {
+                Some(c) => Err(format!("unknown SNAFU digit {:?}", c).into()),
+                None => Ok(line.to_string()),
+            }
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> String {
+        let mut total = lines.iter().fold(0i64, |acc, l| {
+            acc + l.chars().rev().enumerate().fold(0i64, |acc, (i, c)| {
+                let digit = match c {
+                    '2' => 2,
+                    '1' => 1,
+                    '0' => 0,
+                    '-' => -1,
+                    '=' => -2,
+                    _ => panic!("unknown"),
+                };
+                acc + digit * 5i64.pow(i as u32)
+            })
+        });
+        let mut snafu = "".to_string();
+        while total > 0 {
+            let rem = total.rem_euclid(5);
+            total /= 5;
+            if rem <= 2 {
+                snafu.push_str(format!("{}", rem).as_str());
+            } else {
+                snafu.push(if rem == 3 { '=' } else { '-' });
+                total += 1;
+            }
+        }
+        snafu.chars().rev().join("")
+    }
+
+    fn part2(_lines: &Self::Input) -> u32 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day25::parse(&get_example(Day25::DAY)).unwrap();
+        assert_eq!(Day25::part1(&input), "2=-1=0");
+    }
+}
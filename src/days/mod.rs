@@ -0,0 +1,103 @@
+//! One module per puzzle, registered in [`DAYS`] so they can all be run from the `aoc` binary.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day11_bigint;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::{
+    animation::{self, Frames},
+    solution::{self, Answers, Parsed, Result, Solution},
+};
+
+/// A registered solution, type-erased so that every day can be driven the same way.
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>>,
+    /// Parses an input and starts the animation of its simulation, for the animated days
+    pub animate: Option<fn(&str) -> Result<Frames>>,
+}
+
+impl Day {
+    /// Parses `input` and runs the requested part, or both when `part` is `None`.
+    pub fn solve(&self, input: &str, part: Option<u8>) -> Result<Answers> {
+        solution::solve(self.parse, input, part)
+    }
+}
+
+macro_rules! day {
+    ($name:expr, $solution:ty) => {
+        Day {
+            number: <$solution>::DAY,
+            name: $name,
+            parse: solution::parse::<$solution>,
+            animate: None,
+        }
+    };
+    ($name:expr, $solution:ty, animated) => {
+        Day {
+            animate: Some(animation::frames::<$solution>),
+            ..day!($name, $solution)
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!("day01", day01::Day01),
+    day!("day02", day02::Day02),
+    day!("day03", day03::Day03),
+    day!("day04", day04::Day04),
+    day!("day05", day05::Day05),
+    day!("day06", day06::Day06),
+    day!("day07", day07::Day07),
+    day!("day08", day08::Day08),
+    day!("day09", day09::Day09),
+    day!("day10", day10::Day10),
+    day!("day11", day11::Day11),
+    day!("day11-bigint", day11_bigint::Day11BigInt),
+    day!("day12", day12::Day12),
+    day!("day13", day13::Day13),
+    day!("day14", day14::Day14, animated),
+    day!("day15", day15::Day15),
+    day!("day16", day16::Day16),
+    day!("day17", day17::Day17, animated),
+    day!("day18", day18::Day18, animated),
+    day!("day19", day19::Day19),
+    day!("day20", day20::Day20),
+    day!("day21", day21::Day21),
+    day!("day22", day22::Day22, animated),
+    day!("day23", day23::Day23, animated),
+    day!("day24", day24::Day24, animated),
+    day!("day25", day25::Day25),
+];
+
+/// Finds a registered day either by its number (`17`) or by its name (`day11-bigint`).
+pub fn find(query: &str) -> Option<&'static Day> {
+    match query.parse::<u8>() {
+        Ok(number) => DAYS.iter().find(|day| day.number == number),
+        Err(_) => DAYS.iter().find(|day| day.name == query),
+    }
+}
@@ -0,0 +1,36 @@
+use crate::solution::{Result, Solution};
+
+pub struct Day{{DD}};
+
+impl Solution for Day{{DD}} {
+    const DAY: u8 = {{DAY}};
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_lines: &Self::Input) -> u32 {
+        0
+    }
+
+    fn part2(_lines: &Self::Input) -> u32 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::solution::get_example;
+
+    #[test]
+    fn test_example() {
+        let input = Day{{DD}}::parse(&get_example(Day{{DD}}::DAY)).unwrap();
+        assert_eq!(Day{{DD}}::part1(&input), 0);
+        assert_eq!(Day{{DD}}::part2(&input), 0);
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    path::PathBuf,
+};
+
+use crate::input::InputError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Why a single line could not be parsed, before [`parse_lines`] locates it.
+pub type LineError = Box<dyn error::Error + Send + Sync>;
+pub type LineResult<T> = std::result::Result<T, LineError>;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse(ParseError),
+    /// A supporting file, such as `answers.toml`, that could not be read or understood
+    File {
+        path: PathBuf,
+        message: String,
+    },
+    /// A failed exchange with the Advent of Code website
+    Remote {
+        url: String,
+        message: String,
+    },
+}
+
+impl Error {
+    pub fn file(path: impl Into<PathBuf>, message: impl Display) -> Self {
+        Error::File {
+            path: path.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn remote(url: impl Into<String>, message: impl Display) -> Self {
+        Error::Remote {
+            url: url.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(e) => e.fmt(f),
+            Error::Parse(e) => e.fmt(f),
+            Error::File { path, message } => write!(f, "{}: {}", path.display(), message),
+            Error::Remote { url, message } => write!(f, "{}: {}", url, message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Input(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            Error::File { .. } | Error::Remote { .. } => None,
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(e: InputError) -> Self {
+        Error::Input(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// A malformed puzzle input, located by its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, line: usize, text: &str, message: impl Display) -> Self {
+        ParseError {
+            day,
+            line,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day{:02}, line {}: {}: {:?}",
+            self.day, self.line, self.message, self.text
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses every line of `input` with `parse`, failing on the first malformed one.
+pub fn parse_lines<T>(
+    day: u8,
+    input: &str,
+    mut parse: impl FnMut(&str) -> LineResult<T>,
+) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            parse(line).map_err(|e| ParseError::new(day, index + 1, line, e).into())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines(1, "1\n2\n3", |l| Ok(l.parse::<u32>()?)).unwrap(),
+            vec![1, 2, 3]
+        );
+        let Err(Error::Parse(error)) = parse_lines(1, "1\nx2\n3", |l| Ok(l.parse::<u32>()?)) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "x2");
+        assert_eq!(
+            error.to_string(),
+            "day01, line 2: invalid digit found in string: \"x2\""
+        );
+    }
+}
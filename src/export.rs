@@ -0,0 +1,189 @@
+//! Frames saved as pictures: one PNG per frame, or all of them as an animated GIF.
+//!
+//! Each cell becomes a square of `scale` pixels, in the colour of its [`Palette`].
+//!
+//! [`Palette`]: crate::animation::Palette
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    animation::{Frame, Rgb},
+    error::{Error, Result},
+};
+
+/// Size in pixels of the picture of `frame`.
+fn picture_size(frame: &Frame, scale: usize) -> (usize, usize) {
+    (
+        frame.colours.width() * scale,
+        frame.colours.height() * scale,
+    )
+}
+
+/// The pixels of `frame`, row by row, each cell `scale` pixels wide and high.
+fn pixels(frame: &Frame, scale: usize) -> Vec<Rgb> {
+    frame
+        .colours
+        .rows()
+        .flat_map(|row| {
+            let line = row
+                .iter()
+                .flat_map(|&colour| std::iter::repeat_n(colour, scale))
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(line, scale).flatten()
+        })
+        .collect()
+}
+
+/// Saves `frame` as a PNG image at `path`.
+pub fn save_png(frame: &Frame, path: &Path, scale: usize) -> Result<()> {
+    let file = File::create(path).map_err(|e| Error::file(path, e))?;
+    write_png(frame, BufWriter::new(file), scale).map_err(|e| Error::file(path, e))
+}
+
+fn write_png(
+    frame: &Frame,
+    out: impl Write,
+    scale: usize,
+) -> std::result::Result<(), png::EncodingError> {
+    let (width, height) = picture_size(frame, scale);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels(frame, scale).as_flattened())?;
+    writer.finish()
+}
+
+/// Assembles frames of the same size into an animated GIF, looping forever.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    path: PathBuf,
+    scale: usize,
+    size: (usize, usize),
+    /// Delay between two frames, in hundredths of a second
+    delay: u16,
+}
+
+impl GifWriter<BufWriter<File>> {
+    /// Creates the GIF at `path`, sized for `first`, which still has to be written.
+    pub fn create(path: &Path, first: &Frame, scale: usize, fps: u32) -> Result<Self> {
+        let file = File::create(path).map_err(|e| Error::file(path, e))?;
+        GifWriter::new(BufWriter::new(file), path, first, scale, fps)
+    }
+}
+
+impl<W: Write> GifWriter<W> {
+    /// A GIF written to `out`, `path` only naming it in errors.
+    pub fn new(out: W, path: &Path, first: &Frame, scale: usize, fps: u32) -> Result<Self> {
+        let size = picture_size(first, scale);
+        if size.0 > u16::MAX as usize || size.1 > u16::MAX as usize {
+            let message = format!("{}x{} pixels is too large for a GIF", size.0, size.1);
+            return Err(Error::file(path, message));
+        }
+        let error = |e: gif::EncodingError| Error::file(path, e);
+        // No global palette: each frame brings its own
+        let mut encoder =
+            gif::Encoder::new(out, size.0 as u16, size.1 as u16, &[]).map_err(error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(error)?;
+        Ok(GifWriter {
+            encoder,
+            path: path.to_path_buf(),
+            scale,
+            size,
+            delay: (100 / fps.max(1)).max(1) as u16,
+        })
+    }
+
+    pub fn write(&mut self, frame: &Frame) -> Result<()> {
+        let error = |message: String| Error::file(&self.path, message);
+        if picture_size(frame, self.scale) != self.size {
+            return Err(error(format!("frame {:?} has another size", frame.caption)));
+        }
+        let mut palette = HashMap::new();
+        let indices = pixels(frame, self.scale)
+            .into_iter()
+            .map(|colour| {
+                let index = palette.len();
+                *palette.entry(colour).or_insert(index)
+            })
+            .collect::<Vec<_>>();
+        if palette.len() > 256 {
+            return Err(error(format!(
+                "frame {:?} has over 256 colours",
+                frame.caption
+            )));
+        }
+        let mut colours = vec![[0; 3]; palette.len()];
+        for (colour, index) in palette {
+            colours[index] = colour;
+        }
+        let indices = indices.into_iter().map(|i| i as u8).collect::<Vec<_>>();
+        let (width, height) = (self.size.0 as u16, self.size.1 as u16);
+        let mut gif_frame =
+            gif::Frame::from_palette_pixels(width, height, indices, colours.as_flattened(), None);
+        gif_frame.delay = self.delay;
+        self.encoder
+            .write_frame(&gif_frame)
+            .map_err(|e| error(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::animation::tests::frame;
+
+    #[test]
+    fn test_pixels() {
+        let white = [255, 255, 255];
+        let black = [0, 0, 0];
+        assert_eq!(
+            pixels(&frame(""), 2),
+            [
+                [black, black, white, white],
+                [black, black, white, white],
+                [white, white, black, black],
+                [white, white, black, black]
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_png() {
+        let mut out = vec![];
+        write_png(&frame(""), &mut out, 3).unwrap();
+        let decoder = png::Decoder::new(std::io::Cursor::new(out));
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut out = vec![];
+        {
+            let path = Path::new("test.gif");
+            let mut gif = GifWriter::new(&mut out, path, &frame("1"), 2, 10).unwrap();
+            gif.write(&frame("1")).unwrap();
+            gif.write(&frame("2")).unwrap();
+        }
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(std::io::Cursor::new(out))
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (4, 4));
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 10);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+    }
+}
@@ -0,0 +1,374 @@
+//! A rectangular grid of cells, stored row by row, for the puzzles played on a map.
+
+use std::{
+    fmt::{self, Display},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{parse_lines, LineResult, ParseError, Result};
+
+/// A cell position: `row` counted from the top and `col` from the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Coord { row, col }
+    }
+
+    /// The position `delta` (rows, columns) away, unless it would be negative.
+    pub fn offset(self, (d_row, d_col): Delta) -> Option<Coord> {
+        Some(Coord {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// A step between cells, in rows then columns.
+pub type Delta = (isize, isize);
+
+/// Up, right, down and left.
+pub const ORTHOGONAL: [Delta; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// Up-right, down-right, down-left and up-left.
+pub const DIAGONAL: [Delta; 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+/// The eight neighbouring directions, clockwise from up.
+pub const ALL_DIRECTIONS: [Delta; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, panicking when they have different lengths.
+    pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
+        let mut rows = rows.into_iter();
+        let Some(first) = rows.next() else {
+            return Grid {
+                width: 0,
+                height: 0,
+                cells: vec![],
+            };
+        };
+        let mut grid = Grid {
+            width: 0,
+            height: 1,
+            cells: first.into_iter().collect(),
+        };
+        grid.width = grid.cells.len();
+        for row in rows {
+            grid.push_row(row);
+        }
+        grid
+    }
+
+    /// Parses a character map, one row per line, converting each character with `parse`.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut parse: impl FnMut(char) -> LineResult<T>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let rows = parse_lines(day, input, |line| {
+            let row = line
+                .chars()
+                .map(&mut parse)
+                .collect::<LineResult<Vec<T>>>()?;
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err("rows have different lengths".into());
+            }
+            Ok(row)
+        })?;
+        if rows.is_empty() {
+            return Err(ParseError::new(day, 1, "", "empty grid").into());
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.height && coord.col < self.width
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[coord.row * self.width + coord.col])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.contains(coord)
+            .then(|| &mut self.cells[coord.row * self.width + coord.col])
+    }
+
+    /// The position `delta` away from `coord`, if it is inside the grid.
+    pub fn offset(&self, coord: Coord, delta: Delta) -> Option<Coord> {
+        coord.offset(delta).filter(|&c| self.contains(c))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        // `max(1)` keeps `chunks` happy on a grid without columns
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every position, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Coord::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.coords().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(c, _)| c)
+    }
+
+    /// The in-bounds neighbours of `coord` that share an edge with it.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |delta| self.offset(coord, delta))
+    }
+
+    /// The in-bounds neighbours of `coord`, diagonals included.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |delta| self.offset(coord, delta))
+    }
+
+    /// The positions from `coord` (excluded) to the edge of the grid, stepping by `delta`.
+    /// Orthogonal deltas walk along a row or a column, diagonal ones along a diagonal.
+    pub fn ray(&self, coord: Coord, delta: Delta) -> impl Iterator<Item = Coord> + '_ {
+        iter::successors(self.offset(coord, delta), move |&c| self.offset(c, delta))
+    }
+
+    /// Appends a row at the bottom, panicking when its length is not the grid's width.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - len,
+            self.width,
+            "rows have different lengths"
+        );
+        self.height += 1;
+    }
+
+    /// Grows or shrinks the grid to `height` rows, filling new ones with `value`.
+    pub fn resize_rows(&mut self, height: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.resize(height * self.width, value);
+        self.height = height;
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The `width` by `height` part of the grid whose top left corner is `origin`.
+    pub fn subgrid(&self, origin: Coord, width: usize, height: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            origin.row + height <= self.height && origin.col + width <= self.width,
+            "subgrid out of bounds"
+        );
+        Grid::from_rows(
+            (origin.row..origin.row + height)
+                .map(|row| self.row(row)[origin.col..origin.col + width].to_vec()),
+        )
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows((0..self.width).map(|col| self.column(col).cloned()))
+    }
+
+    /// Turns the grid a quarter clockwise: the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows((0..self.width).map(|col| self.column(col).rev().cloned()))
+    }
+
+    /// Turns the grid a quarter counter-clockwise: the right column becomes the top row.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows((0..self.width).rev().map(|col| self.column(col).cloned()))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows(self.rows().rev().map(<[T]>::to_vec))
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{} out of the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{} out of the grid", coord))
+    }
+}
+
+/// Renders the cells row by row, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::Error;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(0, "abc\ndef", Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(1, 2)], 'f');
+        assert_eq!(grid.get(Coord::new(2, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Coord::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+        let Err(Error::Parse(error)) = Grid::parse(0, "ab\nc", Ok) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let corner = grid.neighbours4(Coord::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Coord::new(0, 1), Coord::new(1, 0)]);
+        assert_eq!(grid.neighbours4(Coord::new(0, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_rays() {
+        let grid = sample();
+        let cells = |delta| {
+            grid.ray(Coord::new(0, 0), delta)
+                .map(|c| grid[c])
+                .collect::<String>()
+        };
+        assert_eq!(cells((0, 1)), "bc");
+        assert_eq!(cells((1, 0)), "d");
+        assert_eq!(cells((1, 1)), "e");
+        assert_eq!(cells((-1, 0)), "");
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.subgrid(Coord::new(0, 1), 2, 2).to_string(), "bc\nef");
+        let mut grown = grid.clone();
+        grown.resize_rows(3, 'x');
+        grown.push_row("yzw".chars());
+        assert_eq!(grown.to_string(), "abc\ndef\nxxx\nyzw");
+    }
+}
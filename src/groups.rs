@@ -0,0 +1,219 @@
+//! Inputs made of groups of lines separated by blank lines, read one group at a time, and
+//! statistics over a value per group.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    iter,
+    ops::Range,
+};
+
+use crate::error::{LineResult, ParseError, Result};
+
+/// The groups of lines separated by blank lines, each line with its number, counted from 1.
+/// Several blank lines in a row separate two groups like a single one.
+pub struct Groups<I> {
+    lines: iter::Enumerate<I>,
+}
+
+pub fn groups<I: IntoIterator>(lines: I) -> Groups<I::IntoIter> {
+    Groups {
+        lines: lines.into_iter().enumerate(),
+    }
+}
+
+impl<I, S> Iterator for Groups<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = Vec<(usize, S)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = vec![];
+        for (index, line) in self.lines.by_ref() {
+            if !line.as_ref().is_empty() {
+                group.push((index + 1, line));
+            } else if !group.is_empty() {
+                break;
+            }
+        }
+        (!group.is_empty()).then_some(group)
+    }
+}
+
+/// Parses the groups of `input` one at a time, each line with `parse`. A group fails on its
+/// first malformed line.
+pub fn parse_groups<'a, T>(
+    day: u8,
+    input: &'a str,
+    mut parse: impl FnMut(&str) -> LineResult<T> + 'a,
+) -> impl Iterator<Item = Result<Vec<T>>> + 'a {
+    groups(input.lines()).map(move |group| {
+        group
+            .into_iter()
+            .map(|(line, text)| parse(text).map_err(|e| ParseError::new(day, line, text, e).into()))
+            .collect()
+    })
+}
+
+/// Statistics of one value per group, such as its total, gathered as the groups come.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupStats {
+    count: usize,
+    sum: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+    /// Width of the ranges of values counted together by the histogram
+    bucket: u64,
+    /// Number of values in each range, keyed by its start
+    histogram: BTreeMap<u64, usize>,
+}
+
+impl GroupStats {
+    /// Statistics whose histogram counts the values by ranges `bucket` wide.
+    pub fn new(bucket: u64) -> Self {
+        assert!(bucket > 0, "histogram buckets cannot be empty");
+        GroupStats {
+            count: 0,
+            sum: 0,
+            min: None,
+            max: None,
+            bucket,
+            histogram: BTreeMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, value: u64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        *self
+            .histogram
+            .entry(value / self.bucket * self.bucket)
+            .or_default() += 1;
+    }
+
+    /// Number of groups.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum as f64 / self.count as f64)
+    }
+
+    /// The number of values in each range, from the lowest value's to the highest's,
+    /// empty ranges included.
+    pub fn histogram(&self) -> Vec<(Range<u64>, usize)> {
+        let (Some(min), Some(max)) = (self.min, self.max) else {
+            return vec![];
+        };
+        (min / self.bucket..=max / self.bucket)
+            .map(|index| {
+                let start = index * self.bucket;
+                let count = self.histogram.get(&start).copied().unwrap_or(0);
+                (start..start + self.bucket, count)
+            })
+            .collect()
+    }
+}
+
+/// A summary line, then one line per range of the histogram.
+impl Display for GroupStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Some(min), Some(max), Some(mean)) = (self.min, self.max, self.mean()) else {
+            return write!(f, "no groups");
+        };
+        write!(
+            f,
+            "{} groups, min {}, max {}, mean {:.1}",
+            self.count, min, max, mean
+        )?;
+        let histogram = self.histogram();
+        let labels = histogram
+            .iter()
+            .map(|(range, _)| format!("{}..{}", range.start, range.end))
+            .collect::<Vec<_>>();
+        let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+        let count_width = self.count.to_string().len();
+        for (label, (_, count)) in labels.iter().zip(histogram) {
+            let bar = "#".repeat(count);
+            let line = format!("{:>label_width$} {:>count_width$} {}", label, count, bar);
+            write!(f, "\n{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::{BufRead, Cursor};
+
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_groups() {
+        let lines = "1\n2\n\n3\n\n\n4\n".lines();
+        let groups = groups(lines).collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [vec![(1, "1"), (2, "2")], vec![(4, "3")], vec![(7, "4")]]
+        );
+        // A reader streams its lines the same way
+        let reader = Cursor::new("5\n\n6\n7");
+        let sizes = super::groups(reader.lines().map_while(|l| l.ok()))
+            .map(|group| group.len())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [1, 2]);
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        let parse = |l: &str| Ok(l.parse::<u32>()?);
+        let groups = parse_groups(1, "1\n2\n\n3", parse).collect::<Result<Vec<_>>>();
+        assert_eq!(groups.unwrap(), [vec![1, 2], vec![3]]);
+        let mut groups = parse_groups(1, "1\n\nx\n\n3", parse);
+        assert!(groups.next().unwrap().is_ok());
+        let Some(Err(Error::Parse(error))) = groups.next() else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((error.line, error.text.as_str()), (3, "x"));
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = GroupStats::new(10);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.to_string(), "no groups");
+        for value in [12, 15, 31, 4] {
+            stats.add(value);
+        }
+        assert_eq!((stats.count(), stats.sum()), (4, 62));
+        assert_eq!((stats.min(), stats.max()), (Some(4), Some(31)));
+        assert_eq!(stats.mean(), Some(15.5));
+        assert_eq!(
+            stats.histogram(),
+            [(0..10, 1), (10..20, 2), (20..30, 0), (30..40, 1)]
+        );
+        assert_eq!(
+            stats.to_string(),
+            "4 groups, min 4, max 31, mean 15.5\n 0..10 1 #\n10..20 2 ##\n20..30 0\n30..40 1 #"
+        );
+    }
+}
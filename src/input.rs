@@ -0,0 +1,115 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the directory holding the `dayXX.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// The directory holding the `dayXX.txt` inputs: `$AOC_INPUT_DIR`, or `inputs/`.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Path of the input of `day` in `dir`.
+pub fn input_path(day: u8, dir: &Path) -> PathBuf {
+    dir.join(format!("day{:02}.txt", day))
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Resolves the input of `day`: an explicit `path` (`-` meaning stdin) wins,
+    /// otherwise `dayXX.txt` is looked up in `$AOC_INPUT_DIR`, falling back to `inputs/`.
+    pub fn new(day: u8, path: Option<&str>) -> Self {
+        match path {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::in_dir(day, &input_dir()),
+        }
+    }
+
+    pub fn in_dir(day: u8, dir: &Path) -> Self {
+        InputSource::File(input_path(day, dir))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError {
+            input: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub input: InputSource,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read input {}: {}", self.input, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_resolution() {
+        assert_eq!(InputSource::new(3, Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::new(3, Some("examples/a.txt")),
+            InputSource::File(PathBuf::from("examples/a.txt"))
+        );
+        assert_eq!(
+            InputSource::in_dir(3, Path::new("accounts/bob")),
+            InputSource::File(PathBuf::from("accounts/bob/day03.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file_error_names_path() {
+        let error = InputSource::new(1, Some("does/not/exist.txt"))
+            .read()
+            .unwrap_err();
+        assert!(error.to_string().contains("does/not/exist.txt"));
+    }
+}
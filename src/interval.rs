@@ -0,0 +1,446 @@
+//! Closed intervals of integers, sets of them, and a tree answering overlap queries.
+
+use std::{cmp::Ordering, fmt};
+
+/// The integers from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    /// The interval from `start` to `end`, `None` when it would be empty.
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn point(value: i64) -> Self {
+        Interval {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    /// Number of integers in the interval, saturating for the whole range of `i64`.
+    pub fn size(&self) -> u64 {
+        self.end.abs_diff(self.start).saturating_add(1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every integer of `other` is in this interval.
+    pub fn covers(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Whether the two intervals make a single one, overlapping or side by side.
+    fn joins(&self, other: &Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, held as its maximal intervals in increasing order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximal intervals of the set, in increasing order, never joining each other.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set, saturating for the whole range of `i64`.
+    pub fn coverage(&self) -> u64 {
+        self.intervals.iter().fold(0, |total: u64, interval| {
+            total.saturating_add(interval.size())
+        })
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.find(value).is_ok()
+    }
+
+    /// The index of the interval holding `value`, or where it would be inserted.
+    fn find(&self, value: i64) -> Result<usize, usize> {
+        self.intervals.binary_search_by(|interval| {
+            if interval.end < value {
+                Ordering::Less
+            } else if interval.start > value {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // The intervals joining the new one are merged into it
+        let first = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let last = first
+            + self.intervals[first..]
+                .iter()
+                .take_while(|i| i.joins(&interval))
+                .count();
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| Interval {
+                start: merged.start.min(i.start),
+                end: merged.end.max(i.end),
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut mine, mut theirs) = (self.intervals.iter(), other.intervals.iter());
+        let (mut a, mut b) = (mine.next(), theirs.next());
+        let mut intervals = vec![];
+        while let (Some(x), Some(y)) = (a, b) {
+            intervals.extend(x.intersection(y));
+            // The interval ending first cannot meet any other interval of the other set
+            if x.end < y.end {
+                a = mine.next();
+            } else {
+                b = theirs.next();
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The integers of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        for interval in &self.intervals {
+            let mut rest = Some(*interval);
+            for hole in other.overlapping(interval) {
+                let Some(current) = rest else { break };
+                intervals.extend(before(current.start, hole));
+                rest = after(hole, current.end);
+            }
+            intervals.extend(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The intervals of the set overlapping `interval`.
+    fn overlapping(&self, interval: &Interval) -> &[Interval] {
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        &self.intervals[first..last.max(first)]
+    }
+
+    /// The integers between the intervals of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval> + '_ {
+        // Intervals that do not join leave at least one integer between them
+        self.intervals.windows(2).map(|pair| Interval {
+            start: pair[0].end + 1,
+            end: pair[1].start - 1,
+        })
+    }
+
+    /// The integers of `bounds` missing from the set, in increasing order.
+    pub fn gaps_within(&self, bounds: Interval) -> impl Iterator<Item = Interval> + '_ {
+        let inside = self.overlapping(&bounds);
+        let first = inside
+            .first()
+            .map_or(Some(bounds), |i| before(bounds.start, i));
+        let last = inside.last().and_then(|i| after(i, bounds.end));
+        let between = inside.windows(2).map(|pair| Interval {
+            start: pair[0].end + 1,
+            end: pair[1].start - 1,
+        });
+        first.into_iter().chain(between).chain(last)
+    }
+}
+
+/// The integers from `start` up to just before `interval`, if any.
+fn before(start: i64, interval: &Interval) -> Option<Interval> {
+    Interval::new(start, interval.start.checked_sub(1)?)
+}
+
+/// The integers from just after `interval` up to `end`, if any.
+fn after(interval: &Interval, end: i64) -> Option<Interval> {
+    Interval::new(interval.end.checked_add(1)?, end)
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut sorted: Vec<Interval> = intervals.into_iter().collect();
+        sorted.sort_unstable();
+        let mut merged: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if last.joins(&interval) => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+/// Intervals with a value each, built once to find all the ones overlapping a query in
+/// `O(log n + k)` for `k` results, for many-vs-many overlap searches.
+///
+/// The entries are sorted by start, each seen as the root of the balanced tree made of the
+/// entries around it, and knowing the largest end in that tree.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<V> {
+    entries: Vec<(Interval, V)>,
+    /// The largest end in the subtree rooted at each entry
+    max_ends: Vec<i64>,
+}
+
+impl<V> IntervalTree<V> {
+    pub fn new(entries: impl IntoIterator<Item = (Interval, V)>) -> Self {
+        let mut entries: Vec<(Interval, V)> = entries.into_iter().collect();
+        entries.sort_by_key(|(interval, _)| *interval);
+        let mut max_ends = vec![i64::MIN; entries.len()];
+        Self::index(&entries, &mut max_ends, 0, entries.len());
+        IntervalTree { entries, max_ends }
+    }
+
+    /// Records the largest end of the subtree of `lo..hi`, rooted at its middle.
+    fn index(entries: &[(Interval, V)], max_ends: &mut [i64], lo: usize, hi: usize) -> i64 {
+        if lo >= hi {
+            return i64::MIN;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let left = Self::index(entries, max_ends, lo, mid);
+        let right = Self::index(entries, max_ends, mid + 1, hi);
+        max_ends[mid] = entries[mid].0.end.max(left).max(right);
+        max_ends[mid]
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The entries overlapping `query`, by increasing start.
+    pub fn query(&self, query: Interval) -> Vec<&(Interval, V)> {
+        let mut found = vec![];
+        self.search(query, 0, self.entries.len(), &mut found);
+        found
+    }
+
+    /// The entries holding `value`.
+    pub fn stab(&self, value: i64) -> Vec<&(Interval, V)> {
+        self.query(Interval::point(value))
+    }
+
+    fn search<'a>(
+        &'a self,
+        query: Interval,
+        lo: usize,
+        hi: usize,
+        found: &mut Vec<&'a (Interval, V)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        // Nothing in this subtree reaches the query
+        if self.max_ends[mid] < query.start {
+            return;
+        }
+        self.search(query, lo, mid, found);
+        let entry = &self.entries[mid];
+        // Entries from here on start after the query otherwise
+        if entry.0.start <= query.end {
+            if entry.0.overlaps(&query) {
+                found.push(entry);
+            }
+            self.search(query, mid + 1, hi, found);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(s, e)| interval(s, e)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = interval(2, 8);
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(a.size(), 7);
+        assert!(a.covers(&interval(3, 7)) && !a.covers(&interval(6, 9)));
+        assert!(a.overlaps(&interval(8, 9)) && !a.overlaps(&interval(9, 9)));
+        assert_eq!(a.intersection(&interval(6, 9)), Some(interval(6, 8)));
+        assert_eq!(a.intersection(&interval(9, 10)), None);
+    }
+
+    #[test]
+    fn test_set() {
+        // Adjacent intervals join
+        let s = set(&[(5, 7), (1, 2), (3, 3), (10, 12), (11, 11)]);
+        assert_eq!(s, set(&[(1, 3), (5, 7), (10, 12)]));
+        assert_eq!(s.coverage(), 9);
+        assert!(s.contains(6) && !s.contains(4) && !s.contains(13));
+        assert_eq!(
+            s.gaps().collect::<Vec<_>>(),
+            [interval(4, 4), interval(8, 9)]
+        );
+        assert_eq!(
+            s.gaps_within(interval(0, 20)).collect::<Vec<_>>(),
+            [
+                interval(0, 0),
+                interval(4, 4),
+                interval(8, 9),
+                interval(13, 20)
+            ]
+        );
+        assert_eq!(
+            s.gaps_within(interval(2, 6)).collect::<Vec<_>>(),
+            [interval(4, 4)]
+        );
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut s = set(&[(1, 2), (5, 6), (10, 12)]);
+        s.insert(interval(3, 4));
+        assert_eq!(s, set(&[(1, 6), (10, 12)]));
+        s.insert(interval(8, 8));
+        assert_eq!(s, set(&[(1, 6), (8, 8), (10, 12)]));
+        s.insert(interval(0, 20));
+        assert_eq!(s, set(&[(0, 20)]));
+        let mut empty = IntervalSet::new();
+        empty.insert(interval(1, 1));
+        assert_eq!(empty, IntervalSet::from(interval(1, 1)));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 11), (14, 20)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 11), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (12, 13)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (16, 20)]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_extremes() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let all = interval(min, max);
+        assert_eq!(all.size(), u64::MAX);
+        assert_eq!(IntervalSet::from(all).coverage(), u64::MAX);
+        let ends = set(&[(min, min + 1), (max - 1, max)]);
+        assert_eq!(
+            ends.gaps_within(all).collect::<Vec<_>>(),
+            [interval(min + 2, max - 2)]
+        );
+        assert_eq!(
+            IntervalSet::from(all).difference(&ends),
+            set(&[(min + 2, max - 2)])
+        );
+        assert_eq!(ends.difference(&IntervalSet::from(all)), IntervalSet::new());
+        assert_eq!(
+            set(&[(min, 0)])
+                .gaps_within(interval(min, 1))
+                .collect::<Vec<_>>(),
+            [interval(1, 1)]
+        );
+        let mut joined = set(&[(max, max)]);
+        joined.insert(interval(0, max - 1));
+        assert_eq!(joined, set(&[(0, max)]));
+    }
+
+    #[test]
+    fn test_tree() {
+        let entries = [(1, 3), (2, 9), (5, 6), (8, 8), (12, 15), (4, 4)];
+        let tree = IntervalTree::new(
+            entries
+                .iter()
+                .enumerate()
+                .map(|(index, &(s, e))| (interval(s, e), index)),
+        );
+        let found = |query: Interval| {
+            let mut values = tree
+                .query(query)
+                .iter()
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>();
+            values.sort();
+            values
+        };
+        for query in [
+            interval(0, 0),
+            interval(3, 4),
+            interval(7, 12),
+            interval(16, 20),
+        ] {
+            let expected = (0..entries.len())
+                .filter(|&i| interval(entries[i].0, entries[i].1).overlaps(&query))
+                .collect::<Vec<_>>();
+            assert_eq!(found(query), expected, "query {}", query);
+        }
+        assert_eq!(tree.stab(5).len(), 2);
+    }
+}
@@ -0,0 +1,21 @@
+pub mod animation;
+pub mod answers;
+pub mod batch;
+pub mod client;
+pub mod days;
+pub mod error;
+pub mod export;
+pub mod geometry;
+pub mod grid;
+pub mod groups;
+pub mod input;
+pub mod interval;
+pub mod priority_queue;
+pub mod progress;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submissions;
+pub mod timing;
+pub mod top_k;
+pub mod trace;
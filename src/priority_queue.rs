@@ -1,69 +1,174 @@
+//! A min-priority queue with decrease-key, for Dijkstra-style searches.
+
 use std::{
-    collections::{BinaryHeap, HashSet},
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
     hash::Hash,
 };
 
-struct QueueItem<T> {
-    priority: u32,
+/// A heap entry, ordered by priority only.
+struct QueueItem<T, P> {
+    priority: P,
     node: T,
 }
-impl<T> PartialEq for QueueItem<T> {
+
+impl<T, P: Ord> PartialEq for QueueItem<T, P> {
     fn eq(&self, other: &Self) -> bool {
         self.priority.eq(&other.priority)
     }
 }
 
-impl<T> Eq for QueueItem<T> {}
+impl<T, P: Ord> Eq for QueueItem<T, P> {}
 
-impl<T> PartialOrd for QueueItem<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // Reversed to have a min heap
+impl<T, P: Ord> PartialOrd for QueueItem<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for QueueItem<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+impl<T, P: Ord> Ord for QueueItem<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
         self.priority.cmp(&other.priority)
     }
 }
 
-pub struct PriorityQueue<T> {
-    queue: BinaryHeap<QueueItem<T>>,
-    set: HashSet<T>,
+/// A queue of distinct items, popped from the lowest priority to the highest.
+///
+/// Updating the priority of an item leaves its old entry in the heap: entries that no
+/// longer match the recorded priority of their item are discarded as they reach the top.
+/// Items of equal priority are popped in an unspecified order.
+pub struct PriorityQueue<T, P = u32> {
+    heap: BinaryHeap<Reverse<QueueItem<T, P>>>,
+    priorities: HashMap<T, P>,
+}
+
+impl<T, P> Default for PriorityQueue<T, P>
+where
+    T: Hash + Eq + Clone,
+    P: Ord + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl<T> PriorityQueue<T>
+impl<T, P> PriorityQueue<T, P>
 where
     T: Hash + Eq + Clone,
+    P: Ord + Clone,
 {
     pub fn new() -> Self {
         Self {
-            queue: BinaryHeap::new(),
-            set: HashSet::new(),
+            heap: BinaryHeap::new(),
+            priorities: HashMap::new(),
         }
     }
 
+    /// Number of distinct items in the queue.
+    pub fn len(&self) -> usize {
+        self.priorities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.priorities.is_empty()
+    }
+
     pub fn contains(&self, item: &T) -> bool {
-        self.set.contains(item)
+        self.priorities.contains_key(item)
     }
 
-    pub fn push(&mut self, item: T, priority: u32) {
-        if !self.contains(&item) {
-            self.queue.push(QueueItem {
-                priority,
-                node: item.clone(),
-            });
-            self.set.insert(item.clone());
+    /// Current priority of `item`, if it is queued.
+    pub fn priority(&self, item: &T) -> Option<&P> {
+        self.priorities.get(item)
+    }
+
+    /// Queues `item`, or lowers its priority when it is already queued with a higher one.
+    /// Returns whether the queue changed.
+    pub fn push_or_update(&mut self, item: T, priority: P) -> bool {
+        if let Some(current) = self.priorities.get(&item) {
+            if *current <= priority {
+                return false;
+            }
         }
+        self.priorities.insert(item.clone(), priority.clone());
+        self.heap.push(Reverse(QueueItem {
+            priority,
+            node: item,
+        }));
+        self.discard_stale();
+        true
     }
 
-    pub fn pop(&mut self) -> Option<T> {
-        if let Some(QueueItem { node, .. }) = self.queue.pop() {
-            self.set.remove(&node);
-            Some(node)
-        } else {
-            None
+    /// Lowers the priority of an already queued `item`. Returns whether it was lowered:
+    /// absent items are not queued, and a higher priority is ignored.
+    pub fn decrease_key(&mut self, item: &T, priority: P) -> bool {
+        match self.priorities.get(item) {
+            Some(current) if priority < *current => self.push_or_update(item.clone(), priority),
+            _ => false,
         }
     }
+
+    /// The item with the lowest priority, without removing it.
+    pub fn peek(&self) -> Option<(&T, &P)> {
+        self.heap
+            .peek()
+            .map(|Reverse(QueueItem { node, priority })| (node, priority))
+    }
+
+    /// Removes the item with the lowest priority.
+    pub fn pop(&mut self) -> Option<(T, P)> {
+        let Reverse(QueueItem { node, priority }) = self.heap.pop()?;
+        self.priorities.remove(&node);
+        self.discard_stale();
+        Some((node, priority))
+    }
+
+    /// Drops the outdated entries at the top of the heap, so that it always holds a live one.
+    fn discard_stale(&mut self) {
+        while let Some(Reverse(QueueItem { node, priority })) = self.heap.peek() {
+            if self.priorities.get(node) == Some(priority) {
+                break;
+            }
+            self.heap.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_min_order() {
+        let mut queue = PriorityQueue::new();
+        for (item, priority) in [('c', 3), ('a', 1), ('d', 4), ('b', 2)] {
+            assert!(queue.push_or_update(item, priority));
+        }
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.peek(), Some((&'a', &1)));
+        let popped: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(popped, vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn test_update() {
+        let mut queue: PriorityQueue<&str, i64> = PriorityQueue::new();
+        queue.push_or_update("far", 10);
+        queue.push_or_update("near", 5);
+        assert!(!queue.push_or_update("far", 12));
+        assert!(queue.decrease_key(&"far", 1));
+        assert!(!queue.decrease_key(&"near", 7));
+        assert!(!queue.decrease_key(&"absent", 0));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.priority(&"far"), Some(&1));
+        assert_eq!(queue.pop(), Some(("far", 1)));
+        assert_eq!(queue.pop(), Some(("near", 5)));
+        // The outdated entry of "far" must not resurface
+        assert_eq!(queue.pop(), None);
+        assert!(queue.push_or_update("far", 10));
+        assert_eq!(queue.pop(), Some(("far", 10)));
+    }
 }
@@ -0,0 +1,99 @@
+//! Progress bars for the long searches, drawn on stderr while `aoc run` waits for them.
+//!
+//! Bars are hidden unless switched on with [`set_enabled`], which only the command line does,
+//! so that tests and benchmarks never draw them. They are also hidden when stderr is not a
+//! terminal. A hidden [`Progress`] costs a branch per update.
+//!
+//! A [`Progress`] is shared by reference, so that rayon workers can all advance it:
+//!
+//! ```ignore
+//! let progress = Progress::new(rows.len() as u64, "Scanning rows");
+//! rows.par_iter().for_each(|row| {
+//!     scan(row);
+//!     progress.inc(1);
+//! });
+//! ```
+
+use std::{
+    borrow::Cow,
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether new bars are drawn.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) && io::stderr().is_terminal()
+}
+
+/// The progress of a search, cleared from the terminal once dropped.
+pub struct Progress {
+    bar: Option<ProgressBar>,
+}
+
+impl Progress {
+    /// A bar filling up over `len` steps.
+    pub fn new(len: u64, message: impl Into<Cow<'static, str>>) -> Self {
+        Progress::draw(|| {
+            let style = ProgressStyle::with_template("{msg} [{bar:40}] {pos}/{len} ({eta})")
+                .unwrap()
+                .progress_chars("=> ");
+            ProgressBar::with_draw_target(Some(len), ProgressDrawTarget::stderr())
+                .with_style(style)
+                .with_message(message)
+        })
+    }
+
+    /// A spinner, for searches whose number of steps is unknown.
+    pub fn spinner(message: impl Into<Cow<'static, str>>) -> Self {
+        Progress::draw(|| {
+            let style = ProgressStyle::with_template("{spinner} {msg} ({elapsed})").unwrap();
+            let bar = ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr())
+                .with_style(style)
+                .with_message(message);
+            bar.enable_steady_tick(Duration::from_millis(100));
+            bar
+        })
+    }
+
+    fn draw(bar: impl FnOnce() -> ProgressBar) -> Self {
+        Progress {
+            bar: enabled().then(bar),
+        }
+    }
+
+    pub fn inc(&self, delta: u64) {
+        if let Some(bar) = &self.bar {
+            bar.inc(delta);
+        }
+    }
+
+    /// Replaces the message, formatting it only when the bar is drawn.
+    pub fn set_message(&self, message: impl FnOnce() -> String) {
+        if let Some(bar) = &self.bar {
+            bar.set_message(message());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_hidden() {
+        let progress = Progress::new(10, "Hidden");
+        assert!(progress.bar.is_none());
+        progress.inc(1);
+        progress.set_message(|| unreachable!("hidden bars are never formatted"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Starting the solution of a new day: the module generated from `src/days/template.rs`,
+//! its registration in [`DAYS`](crate::days::DAYS), and empty fixtures for its example and
+//! its answers.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::ANSWERS_FILE,
+    error::{Error, Result},
+    input::input_path,
+};
+
+/// The solution template, relative to the repository root. `{{DD}}` stands for the
+/// two-digit day and `{{DAY}}` for its number.
+pub const TEMPLATE_FILE: &str = "src/days/template.rs";
+const DAYS_DIR: &str = "src/days";
+const EXAMPLES_DIR: &str = "inputs/examples";
+
+/// The template with the day substituted.
+pub fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{DD}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Inserts `line` into the block of lines that `key` selects, keeping that block sorted.
+/// Returns `None` when `text` has no such block.
+fn insert_sorted(text: &str, line: &str, key: impl Fn(&str) -> Option<&str>) -> Option<String> {
+    let new_key = key(line)?;
+    let mut lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &l)| Some((index, key(l)?)))
+        .collect();
+    let index = match keyed.iter().find(|&&(_, k)| k > new_key) {
+        Some(&(index, _)) => index,
+        None => keyed.last()?.0 + 1,
+    };
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Declares the module of `day` and adds it to `DAYS` in the source of `src/days/mod.rs`.
+pub fn register(days_mod: &str, day: u8) -> Option<String> {
+    let name = format!("day{:02}", day);
+    let declaration = format!("pub mod {};", name);
+    let entry = format!("    day!(\"{}\", {}::Day{:02}),", name, name, day);
+    let declared = insert_sorted(days_mod, &declaration, |l| {
+        l.strip_prefix("pub mod ")?.strip_suffix(';')
+    })?;
+    insert_sorted(&declared, &entry, |l| {
+        l.trim_start().strip_prefix("day!(\"")?.split('"').next()
+    })
+}
+
+/// Adds an empty `[dayXX]` table to the answers file, before the next day's.
+fn add_answers(answers: &str, day: u8) -> String {
+    let header = format!("[day{:02}]", day);
+    let mut lines: Vec<&str> = answers.lines().collect();
+    match lines
+        .iter()
+        .position(|l| l.starts_with("[day") && *l > header.as_str())
+    {
+        Some(index) => lines.splice(index..index, [header.as_str(), ""]),
+        None => lines.splice(lines.len().., ["", header.as_str()]),
+    };
+    lines.join("\n").trim_start().to_string() + "\n"
+}
+
+/// Creates the module of `day` in the repository at `root`, registers it, and creates its
+/// empty example and answers. Nothing is written when the day already exists.
+/// Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let read = |path: &Path| fs::read_to_string(path).map_err(|e| Error::file(path, e));
+    let module = root.join(DAYS_DIR).join(format!("{}.rs", name));
+    let days_mod = root.join(DAYS_DIR).join("mod.rs");
+    let example = input_path(day, &root.join(EXAMPLES_DIR));
+    let answers = root.join(ANSWERS_FILE);
+
+    let registry = read(&days_mod)?;
+    if module.exists() || registry.lines().any(|l| l == format!("pub mod {};", name)) {
+        return Err(Error::file(&module, format!("{} already exists", name)));
+    }
+    if example.exists() {
+        return Err(Error::file(&example, "example already exists"));
+    }
+    let answer_sheet = if answers.exists() {
+        read(&answers)?
+    } else {
+        String::new()
+    };
+    if answer_sheet.lines().any(|l| l == format!("[{}]", name)) {
+        return Err(Error::file(
+            &answers,
+            format!("{} already has answers", name),
+        ));
+    }
+    let registry = register(&registry, day)
+        .ok_or_else(|| Error::file(&days_mod, "no `pub mod` declarations or `DAYS` entries"))?;
+    let source = render(&read(&root.join(TEMPLATE_FILE))?, day);
+
+    let write = |path: &Path, content: &str| -> Result<PathBuf> {
+        fs::write(path, content).map_err(|e| Error::file(path, e))?;
+        Ok(path.to_path_buf())
+    };
+    if let Some(dir) = example.parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::file(dir, e))?;
+    }
+    Ok(vec![
+        write(&module, &source)?,
+        write(&days_mod, &registry)?,
+        write(&example, "")?,
+        write(&answers, &add_answers(&answer_sheet, day))?,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::client::tests::temp_dir;
+
+    const DAYS_MOD: &str = "pub mod day01;\npub mod day03;\n\nuse crate::solution;\n\n\
+                            pub const DAYS: &[Day] = &[\n    day!(\"day01\", day01::Day01),\n    \
+                            day!(\"day03\", day03::Day03),\n];\n";
+
+    #[test]
+    fn test_render() {
+        let source = render(include_str!("days/template.rs"), 7);
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("const DAY: u8 = 7;"));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(DAYS_MOD, 2).unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\nuse crate::solution;\n\n\
+             pub const DAYS: &[Day] = &[\n    day!(\"day01\", day01::Day01),\n    \
+             day!(\"day02\", day02::Day02),\n    day!(\"day03\", day03::Day03),\n];\n"
+        );
+        assert!(register(DAYS_MOD, 4).unwrap().contains(
+            "    day!(\"day03\", day03::Day03),\n    day!(\"day04\", day04::Day04),\n];"
+        ));
+        assert_eq!(register("", 1), None);
+    }
+
+    #[test]
+    fn test_add_answers() {
+        let answers = "[day01]\npart1 = 1\n\n[day03]\npart1 = 3\n";
+        assert_eq!(
+            add_answers(answers, 2),
+            "[day01]\npart1 = 1\n\n[day02]\n\n[day03]\npart1 = 3\n"
+        );
+        assert_eq!(
+            add_answers(answers, 4),
+            "[day01]\npart1 = 1\n\n[day03]\npart1 = 3\n\n[day04]\n"
+        );
+        assert_eq!(add_answers("", 1), "[day01]\n");
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = temp_dir("new-day");
+        fs::create_dir_all(root.join(DAYS_DIR)).unwrap();
+        fs::write(root.join(DAYS_DIR).join("mod.rs"), DAYS_MOD).unwrap();
+        fs::write(root.join(TEMPLATE_FILE), include_str!("days/template.rs")).unwrap();
+
+        let written = new_day(&root, 2).unwrap();
+        assert_eq!(written.len(), 4);
+        let module = fs::read_to_string(root.join("src/days/day02.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day02"));
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/examples/day02.txt")).unwrap(),
+            ""
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(ANSWERS_FILE)).unwrap(),
+            "[day02]\n"
+        );
+
+        // A second attempt must leave the day as it is
+        fs::write(root.join("src/days/day02.rs"), "// solved").unwrap();
+        assert!(new_day(&root, 2).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day02.rs")).unwrap(),
+            "// solved"
+        );
+        assert!(new_day(&root, 1).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,225 @@
+//! Shortest path searches over implicit graphs, given as a successor function.
+//!
+//! Every search starts from one or more states, all at distance zero, and stops as soon as
+//! it reaches a state accepted by `is_goal` (pass `|_| false` to explore everything that is
+//! reachable). The returned [`Search`] keeps the distance and the predecessor of each state
+//! reached, from which paths are rebuilt.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::priority_queue::PriorityQueue;
+
+/// The outcome of a search.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S, C> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy,
+{
+    /// The goal state that stopped the search, if one was reached.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Distance from the closest start to the goal.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// Distance from the closest start to `state`. It is final for every state when the
+    /// whole graph was explored; when the search stopped at a goal, states further away
+    /// than the goal may only have an upper bound.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Every state reached, with its distance.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, C)> + '_ {
+        self.distances.iter().map(|(state, &d)| (state, d))
+    }
+
+    /// The states from a start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().expect("path is not empty")) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The states from a start to the goal, both included.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search, where every move costs one.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let distance = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, for successors with non-negative costs.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search, guided by a `heuristic` that must never overestimate the distance to a goal.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = PriorityQueue::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        search.distances.insert(start.clone(), C::default());
+        queue.push_or_update(start, estimate);
+    }
+    while let Some((state, _)) = queue.pop() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let distance = search.distances[&state];
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_distance)
+            {
+                continue;
+            }
+            let estimate = next_distance + heuristic(&next);
+            search.distances.insert(next.clone(), next_distance);
+            search.parents.insert(next.clone(), state.clone());
+            queue.push_or_update(next, estimate);
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::geometry::Point2;
+
+    /// A 5x5 room with a wall on x = 2, open at the bottom.
+    fn room_successors(p: &Point2) -> Vec<Point2> {
+        p.neighbours4()
+            .filter(|n| (0..5).contains(&n.x) && (0..5).contains(&n.y))
+            .filter(|n| n.x != 2 || n.y == 4)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let goal = Point2::new(4, 0);
+        let search = bfs([Point2::ORIGIN], room_successors, |&p| p == goal);
+        assert_eq!(search.goal_distance(), Some(12));
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!(path[0], Point2::ORIGIN);
+        assert!(path.contains(&Point2::new(2, 4)));
+
+        let everything = bfs([Point2::ORIGIN], room_successors, |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.distances().count(), 21);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let starts = [Point2::ORIGIN, Point2::new(4, 4)];
+        let search = bfs(starts, room_successors, |&p| p == Point2::new(4, 0));
+        assert_eq!(search.goal_distance(), Some(4));
+        assert_eq!(search.goal_path().unwrap()[0], Point2::new(4, 4));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // The direct edge a -> c is more expensive than going through b
+        let successors = |s: &char| match s {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 2)],
+            _ => vec![],
+        };
+        let search = dijkstra(['a'], successors, |&s| s == 'c');
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec!['a', 'b', 'c']));
+
+        let goal = Point2::new(4, 0);
+        let search = astar(
+            [Point2::ORIGIN],
+            |p| room_successors(p).into_iter().map(|n| (n, 1)),
+            |p| p.manhattan(goal),
+            |&p| p == goal,
+        );
+        assert_eq!(search.goal_distance(), Some(12));
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::input::InputSource;
+
+pub use crate::error::Result;
+
+/// A puzzle solution: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+/// The answer of a part that only some inputs have, or why this input has none. Parts
+/// return it instead of panicking when their input parsed but does not suit them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Attempt<T> {
+    Solved(T),
+    Unsolvable(String),
+}
+
+impl<T: Display> Display for Attempt<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Attempt::Solved(answer) => answer.fmt(f),
+            Attempt::Unsolvable(reason) => write!(f, "unsolvable: {}", reason),
+        }
+    }
+}
+
+impl<T, E: Display> From<std::result::Result<T, E>> for Attempt<T> {
+    fn from(result: std::result::Result<T, E>) -> Self {
+        match result {
+            Ok(answer) => Attempt::Solved(answer),
+            Err(e) => Attempt::Unsolvable(e.to_string()),
+        }
+    }
+}
+
+/// A part's answer, rendered, with the time it took to compute.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub value: String,
+    pub time: Duration,
+}
+
+/// Answers produced by [`solve`], `None` for the parts that were not requested.
+#[derive(Debug, Default)]
+pub struct Answers {
+    pub parse_time: Duration,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+/// A parsed input, type-erased so that the parts of any day can be run the same way.
+pub trait Parsed {
+    fn part1(&self) -> Box<dyn Display>;
+    fn part2(&self) -> Box<dyn Display>;
+}
+
+struct ParsedInput<S: Solution> {
+    input: S::Input,
+    solution: PhantomData<S>,
+}
+
+impl<S: Solution> Parsed for ParsedInput<S>
+where
+    S::Output1: 'static,
+    S::Output2: 'static,
+{
+    fn part1(&self) -> Box<dyn Display> {
+        Box::new(S::part1(&self.input))
+    }
+
+    fn part2(&self) -> Box<dyn Display> {
+        Box::new(S::part2(&self.input))
+    }
+}
+
+pub fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>>
+where
+    S::Output1: 'static,
+    S::Output2: 'static,
+{
+    Ok(Box::new(ParsedInput::<S> {
+        input: S::parse(input)?,
+        solution: PhantomData,
+    }))
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Parses `input` with `parse` and runs the requested part, or both when `part` is `None`.
+pub fn solve(
+    parse: fn(&str) -> Result<Box<dyn Parsed>>,
+    input: &str,
+    part: Option<u8>,
+) -> Result<Answers> {
+    let (parsed, parse_time) = timed(|| parse(input));
+    let parsed = parsed?;
+    let mut answers = Answers {
+        parse_time,
+        ..Answers::default()
+    };
+    if part.is_none_or(|p| p == 1) {
+        let (value, time) = timed(|| parsed.part1());
+        answers.part1 = Some(Answer {
+            value: value.to_string(),
+            time,
+        });
+    }
+    if part.is_none_or(|p| p == 2) {
+        let (value, time) = timed(|| parsed.part2());
+        answers.part2 = Some(Answer {
+            value: value.to_string(),
+            time,
+        });
+    }
+    Ok(answers)
+}
+
+/// Reads the default input of `day`, panicking with the tried path when it is missing.
+pub fn get_input(day: u8) -> String {
+    InputSource::new(day, None)
+        .read()
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads the published example of `day`, stored in `inputs/examples/`.
+pub fn get_example(day: u8) -> String {
+    InputSource::in_dir(day, Path::new("inputs/examples"))
+        .read()
+        .unwrap_or_else(|e| panic!("{}", e))
+}
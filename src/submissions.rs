@@ -0,0 +1,202 @@
+//! The answers submitted to the website and how they were judged, so that a value known to
+//! be wrong is never submitted twice.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Outcome,
+    error::{Error, Result},
+};
+
+/// Default location of the submission log, relative to the repository root.
+pub const SUBMISSIONS_FILE: &str = "submissions.toml";
+
+/// The judged submissions of one part.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PartLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<String>,
+    /// Wrong answers that came without a hint
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayLog {
+    #[serde(skip_serializing_if = "PartLog::is_empty")]
+    pub part1: PartLog,
+    #[serde(skip_serializing_if = "PartLog::is_empty")]
+    pub part2: PartLog,
+}
+
+/// What the log already knows about an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Known {
+    /// Never judged, worth submitting
+    New,
+    /// Already accepted
+    Correct,
+    Wrong {
+        reason: String,
+    },
+}
+
+impl PartLog {
+    fn is_empty(&self) -> bool {
+        *self == PartLog::default()
+    }
+
+    /// Judges `answer` from the earlier submissions. Numbers are also compared with the
+    /// answers that were too high or too low.
+    pub fn check(&self, answer: &str) -> Known {
+        let wrong = |reason: String| Known::Wrong { reason };
+        if let Some(correct) = &self.correct {
+            return if correct == answer {
+                Known::Correct
+            } else {
+                wrong(format!("the accepted answer is {}", correct))
+            };
+        }
+        if self.wrong.iter().any(|w| w == answer) {
+            return wrong("it was already rejected".to_string());
+        }
+        if let Some(high) = first_beyond(&self.too_high, answer, true) {
+            return wrong(format!("{} was already too high", high));
+        }
+        if let Some(low) = first_beyond(&self.too_low, answer, false) {
+            return wrong(format!("{} was already too low", low));
+        }
+        Known::New
+    }
+
+    /// Remembers how `answer` was judged. Outcomes that are not a verdict are ignored.
+    pub fn record(&mut self, answer: &str, outcome: Outcome) {
+        let answer = answer.to_string();
+        match outcome {
+            Outcome::Correct => self.correct = Some(answer),
+            Outcome::TooHigh => self.too_high.push(answer),
+            Outcome::TooLow => self.too_low.push(answer),
+            Outcome::Wrong => self.wrong.push(answer),
+            Outcome::TooSoon | Outcome::WrongLevel => (),
+        }
+    }
+}
+
+/// The first of `values` that `answer` reaches: by value for numbers, above them when they
+/// were `too_high` and below them otherwise, and by text for anything else.
+fn first_beyond<'a>(values: &'a [String], answer: &str, too_high: bool) -> Option<&'a String> {
+    values.iter().find(
+        |value| match (answer.parse::<i128>(), value.parse::<i128>()) {
+            (Ok(a), Ok(v)) => (too_high && a >= v) || (!too_high && a <= v),
+            _ => *value == answer,
+        },
+    )
+}
+
+/// The submissions of each day, keyed by day name:
+///
+/// ```toml
+/// [day01.part1]
+/// correct = "67622"
+/// too_low = ["6762"]
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubmissionLog {
+    days: BTreeMap<String, DayLog>,
+}
+
+impl SubmissionLog {
+    /// Reads the log, a missing file being an empty log.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::file(path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(Error::file(path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string(self).map_err(|e| Error::file(path, e))?;
+        fs::write(path, content).map_err(|e| Error::file(path, e))
+    }
+
+    pub fn part(&self, day: &str, part: u8) -> Option<&PartLog> {
+        let log = self.days.get(day)?;
+        match part {
+            1 => Some(&log.part1),
+            2 => Some(&log.part2),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, day: &str, part: u8, answer: &str) -> Known {
+        self.part(day, part)
+            .map_or(Known::New, |log| log.check(answer))
+    }
+
+    pub fn record(&mut self, day: &str, part: u8, answer: &str, outcome: Outcome) {
+        let log = self.days.entry(day.to_string()).or_default();
+        let part = match part {
+            1 => &mut log.part1,
+            2 => &mut log.part2,
+            _ => panic!("invalid part {}", part),
+        };
+        part.record(answer, outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn reason(log: &SubmissionLog, answer: &str) -> String {
+        match log.check("day01", 1, answer) {
+            Known::Wrong { reason } => reason,
+            known => panic!("{} is {:?}", answer, known),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut log = SubmissionLog::default();
+        assert_eq!(log.check("day01", 1, "100"), Known::New);
+        log.record("day01", 1, "100", Outcome::TooHigh);
+        log.record("day01", 1, "20", Outcome::TooLow);
+        log.record("day01", 1, "50", Outcome::Wrong);
+        log.record("day01", 1, "60", Outcome::TooSoon);
+        assert_eq!(reason(&log, "150"), "100 was already too high");
+        assert_eq!(reason(&log, "20"), "20 was already too low");
+        assert_eq!(reason(&log, "50"), "it was already rejected");
+        assert_eq!(log.check("day01", 1, "60"), Known::New);
+        assert_eq!(log.check("day01", 2, "60"), Known::New);
+
+        log.record("day01", 1, "60", Outcome::Correct);
+        assert_eq!(log.check("day01", 1, "60"), Known::Correct);
+        assert_eq!(reason(&log, "61"), "the accepted answer is 60");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut log = SubmissionLog::default();
+        log.record("day05", 1, "CMZ", Outcome::Wrong);
+        log.record("day05", 1, "TQRFCBSJJ", Outcome::Correct);
+        let content = toml::to_string(&log).unwrap();
+        assert_eq!(
+            content,
+            "[day05.part1]\ncorrect = \"TQRFCBSJJ\"\nwrong = [\"CMZ\"]\n"
+        );
+        let read: SubmissionLog = toml::from_str(&content).unwrap();
+        assert_eq!(read.check("day05", 1, "CMZ"), log.check("day05", 1, "CMZ"));
+        assert_eq!(read.part("day05", 1), log.part("day05", 1));
+    }
+}
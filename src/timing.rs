@@ -0,0 +1,183 @@
+//! Timing reports of a batch of days, as a table for humans or as JSON/CSV for scripts.
+
+use std::{fmt::Write, io, time::Duration};
+
+use serde::Serialize;
+
+use crate::solution::Answers;
+
+/// Total time above which a day is highlighted as slow in the table.
+pub const SLOW: Duration = Duration::from_secs(1);
+/// Total time above which a day is highlighted as worth a look in the table.
+pub const NOTICEABLE: Duration = Duration::from_millis(100);
+
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Times of one day, `None` for the parts that were not run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: &'static str,
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timing {
+    pub fn new(day: &'static str, answers: &Answers) -> Self {
+        Timing {
+            day,
+            parse: answers.parse_time,
+            part1: answers.part1.as_ref().map(|a| a.time),
+            part2: answers.part2.as_ref().map(|a| a.time),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+/// A row of the machine-readable reports, with every time in nanoseconds.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: &'a str,
+    parse_ns: u64,
+    part1_ns: Option<u64>,
+    part2_ns: Option<u64>,
+    total_ns: u64,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl<'a> From<&'a Timing> for Record<'a> {
+    fn from(timing: &'a Timing) -> Self {
+        Record {
+            day: timing.day,
+            parse_ns: nanos(timing.parse),
+            part1_ns: timing.part1.map(nanos),
+            part2_ns: timing.part2.map(nanos),
+            total_ns: nanos(timing.total()),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+fn format_row(cells: [&str; 5]) -> String {
+    let [day, parse, part1, part2, total] = cells;
+    format!(
+        "{:<14} {:>10} {:>10} {:>10} {:>10}",
+        day, parse, part1, part2, total
+    )
+}
+
+/// Renders the timings as an aligned table ending with the overall total. With `colour`,
+/// rows are highlighted by cost: red above [`SLOW`], yellow above [`NOTICEABLE`].
+pub fn table(timings: &[Timing], colour: bool) -> String {
+    let optional = |time: Option<Duration>| time.map_or("-".to_string(), format_duration);
+    let mut table = format_row(["day", "parse", "part1", "part2", "total"]);
+    table.push('\n');
+    for timing in timings {
+        let row = format_row([
+            timing.day,
+            &format_duration(timing.parse),
+            &optional(timing.part1),
+            &optional(timing.part2),
+            &format_duration(timing.total()),
+        ]);
+        let highlight = match timing.total() {
+            total if total >= SLOW => Some(RED),
+            total if total >= NOTICEABLE => Some(YELLOW),
+            _ => None,
+        };
+        match highlight {
+            Some(code) if colour => writeln!(table, "{}{}{}", code, row, RESET),
+            _ => writeln!(table, "{}", row),
+        }
+        .expect("writing to a String cannot fail");
+    }
+    let total = timings.iter().map(Timing::total).sum();
+    table.push_str(&format_row(["total", "", "", "", &format_duration(total)]));
+    table.push('\n');
+    table
+}
+
+pub fn json(timings: &[Timing]) -> String {
+    let records: Vec<Record> = timings.iter().map(Record::from).collect();
+    serde_json::to_string_pretty(&records).expect("timings are always serializable") + "\n"
+}
+
+pub fn csv(timings: &[Timing]) -> io::Result<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for timing in timings {
+        writer.serialize(Record::from(timing))?;
+    }
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8(bytes).expect("the records are UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn timings() -> Vec<Timing> {
+        vec![
+            Timing {
+                day: "day01",
+                parse: Duration::from_micros(80),
+                part1: Some(Duration::from_micros(4)),
+                part2: Some(Duration::from_micros(16)),
+            },
+            Timing {
+                day: "day19",
+                parse: Duration::from_micros(20),
+                part1: None,
+                part2: Some(Duration::from_secs(3)),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_total() {
+        assert_eq!(timings()[0].total(), Duration::from_micros(100));
+        assert_eq!(timings()[1].total(), Duration::from_micros(3_000_020));
+    }
+
+    #[test]
+    fn test_table() {
+        let table = table(&timings(), true);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("day01 "));
+        assert!(!lines[1].contains(RED));
+        assert!(lines[2].starts_with(RED));
+        assert!(lines[2].contains(" - "));
+        assert!(lines[3].starts_with("total ") && lines[3].ends_with("3.00s"));
+        assert!(!super::table(&timings(), false).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&timings()).unwrap(),
+            "day,parse_ns,part1_ns,part2_ns,total_ns\n\
+             day01,80000,4000,16000,100000\n\
+             day19,20000,,3000000000,3000020000\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let value: serde_json::Value = serde_json::from_str(&json(&timings())).unwrap();
+        assert_eq!(value[0]["day"], "day01");
+        assert_eq!(value[0]["total_ns"], 100000);
+        assert!(value[1]["part1_ns"].is_null());
+    }
+}
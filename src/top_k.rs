@@ -0,0 +1,100 @@
+//! The largest items of a stream, without keeping the others.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Keeps the `k` largest items pushed into it, in `O(log k)` per item.
+///
+/// The ordering is the one of `T`: push [`Reverse`] items to keep the smallest ones
+/// instead. Among equal items, the first ones pushed are kept.
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    k: usize,
+    /// A min-heap, its top being the first item to drop
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if item > smallest.0 {
+                *smallest = Reverse(item);
+            }
+        }
+    }
+
+    /// Number of items kept, at most `k`.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// The smallest item kept, the first to go when a larger one comes.
+    pub fn threshold(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(item)| item)
+    }
+
+    /// The items kept, from the largest to the smallest.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorted ascending on `Reverse`, hence descending on the items
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_largest() {
+        let mut top = TopK::new(3);
+        assert_eq!(top.threshold(), None);
+        top.extend([5, 1, 9, 3, 7, 9, 2]);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.threshold(), Some(&7));
+        assert_eq!(top.into_sorted_vec(), [9, 9, 7]);
+    }
+
+    #[test]
+    fn test_smallest() {
+        let mut top = TopK::new(2);
+        top.extend([5, 1, 9, 3].map(Reverse));
+        let smallest = top.into_sorted_vec().into_iter().map(|Reverse(n)| n);
+        assert_eq!(smallest.collect::<Vec<_>>(), [1, 3]);
+    }
+
+    #[test]
+    fn test_fewer_than_k() {
+        let mut top = TopK::new(3);
+        top.push("b");
+        top.push("a");
+        assert_eq!(top.into_sorted_vec(), ["b", "a"]);
+        let mut none = TopK::new(0);
+        none.push(1);
+        assert!(none.is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+//! Step-by-step output of the solutions, switched on with `aoc run --trace`.
+//!
+//! Tracing is off unless a level is set, so that benchmarks and plain runs only pay for
+//! checking it. Traces go to stderr, keeping stdout for the answers.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use clap::ValueEnum;
+
+/// How much is traced, each level including the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+#[repr(u8)]
+pub enum Level {
+    Off,
+    /// Milestones, such as the best route or a detected cycle
+    Summary,
+    /// One line per step of a simulation
+    Steps,
+    /// A drawing of the whole state at each step
+    Frames,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether traces of `level` are shown.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Prints a line to stderr when the given [`Level`] is enabled, formatting it only then:
+///
+/// ```ignore
+/// trace!(Steps, "Cycle {} -> rx={}", cycle, rx);
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            eprintln!($($arg)+);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert!(!enabled(Level::Summary));
+        set_level(Level::Steps);
+        assert!(enabled(Level::Summary) && enabled(Level::Steps));
+        assert!(!enabled(Level::Frames));
+        assert!(!enabled(Level::Off));
+        set_level(Level::Off);
+        assert!(!enabled(Level::Summary));
+    }
+}